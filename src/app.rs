@@ -1,31 +1,66 @@
-use std::{rc::Rc, time::Duration};
+use std::{path::PathBuf, rc::Rc, sync::Arc, time::Duration};
 mod action;
-mod fetch;
+pub mod apply;
+pub mod backup;
+pub mod compare;
+mod connectors;
+pub mod copy;
+pub mod fetch;
+mod health;
 pub mod model;
 mod msg;
+mod participants;
+mod secrets;
 
 use crossterm::event::{self, Event, KeyCode};
-use edc_connector_client::{Auth, EdcConnectorClient, OAuth2Config};
+use edc_connector_client::types::query::Query;
 use futures::FutureExt;
-use keyring::Entry;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
+use reqwest::Client;
 
 use crate::{
+    client::{
+        oauth::{ClientCredential, OAuth2, OAuth2Settings},
+        transport, ClientAuth,
+    },
     components::{
-        agreements::ContractAgreementsComponent, assets::AssetsComponent,
-        connectors::ConnectorsComponent, contract_definitions::ContractDefinitionsComponent,
-        contract_negotiations::ContractNegotiationsComponent, dataplanes::DataPlanesComponent,
-        edrs::EdrsComponent, footer::Footer, header::HeaderComponent, launch_bar::LaunchBar,
-        policies::PolicyDefinitionsComponent, transfer_processes::TransferProcessesComponent,
-        Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification, NotificationMsg,
+        agreements::ContractAgreementsComponent,
+        api_error::ApiErrorComponent,
+        apply::ApplyComponent,
+        assets::AssetsComponent,
+        compare::CompareComponent,
+        config_error::{ConfigErrorComponent, ConfigErrorProps},
+        connectors::{msg::ConnectorsMsg, ConnectorsComponent},
+        contract_definitions::ContractDefinitionsComponent,
+        contract_negotiations::ContractNegotiationsComponent,
+        copy::CopyComponent,
+        credentials::CredentialsComponent,
+        dashboard::DashboardComponent,
+        dataplanes::DataPlanesComponent,
+        edrs::EdrsComponent,
+        footer::Footer,
+        header::HeaderComponent,
+        launch_bar::LaunchBar,
+        notifications::NotificationsComponent,
+        participants::{ParticipantContextResources, ParticipantContextsComponent},
+        policies::PolicyDefinitionsComponent,
+        requests::RequestsComponent,
+        transfer_processes::TransferProcessesComponent,
+        Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
+        NotificationMsg, StatelessComponent,
+    },
+    config::{
+        watch::ConfigWatcher, AuthKind, Config, ConfigError, ConnectorConfig, OAuthClientAuth,
     },
-    config::{AuthKind, Config, ConnectorConfig},
     types::{
-        connector::{Connector, ConnectorStatus},
+        api_error::ApiError,
+        connector::{Connector, ConnectorStatus, Credentials},
+        history::StateHistory,
         info::InfoSheet,
+        manifest::ManifestKind,
         nav::{Menu, Nav},
     },
 };
@@ -36,6 +71,7 @@ const SERVICE: &str = "edc-connector-tui";
 
 pub struct App {
     connectors: ConnectorsComponent,
+    dashboard: DashboardComponent,
     policies: PolicyDefinitionsComponent,
     assets: AssetsComponent,
     contract_definitions: ContractDefinitionsComponent,
@@ -44,129 +80,264 @@ pub struct App {
     transfer_processes: TransferProcessesComponent,
     edrs: EdrsComponent,
     dataplanes: DataPlanesComponent,
+    participant_contexts: ParticipantContextsComponent,
+    requests: RequestsComponent,
+    notifications: NotificationsComponent,
+    apply: ApplyComponent,
+    compare: CompareComponent,
     launch_bar: LaunchBar,
     launch_bar_visible: bool,
     focus: AppFocus,
     header: HeaderComponent,
     footer: Footer,
+    credentials: CredentialsComponent,
+    copy: CopyComponent,
+    config_watcher: Option<Arc<ConfigWatcher>>,
+    config_error: Option<ConfigError>,
+    error: Option<ApiError>,
 }
 
 impl App {
-    fn auth(cfg: &ConnectorConfig) -> (ConnectorStatus, Auth) {
-        match cfg.auth() {
-            AuthKind::NoAuth => (ConnectorStatus::Connected, Auth::NoAuth),
-            AuthKind::Token { token_alias } => {
-                let entry = Entry::new(SERVICE, token_alias).and_then(|entry| entry.get_password());
-
-                match entry {
-                    Ok(pwd) => (ConnectorStatus::Connected, Auth::api_token(pwd)),
-                    Err(_err) => (
-                        ConnectorStatus::Custom(format!(
-                            "Token not found for alias {}",
-                            token_alias
-                        )),
-                        Auth::NoAuth,
-                    ),
-                }
-            }
+    async fn auth(cfg: &ConnectorConfig, http: &Client) -> (ConnectorStatus, ClientAuth) {
+        match secrets::resolve(cfg).await {
+            Ok(Some(secret)) => Self::auth_with_secret(cfg, http, secret),
+            Ok(None) => (ConnectorStatus::Unknown, ClientAuth::NoAuth),
+            Err(err) => (ConnectorStatus::MissingSecret(err), ClientAuth::NoAuth),
+        }
+    }
+
+    fn auth_with_secret(
+        cfg: &ConnectorConfig,
+        http: &Client,
+        secret: String,
+    ) -> (ConnectorStatus, ClientAuth) {
+        let auth = match cfg.auth() {
+            AuthKind::NoAuth => ClientAuth::NoAuth,
+            AuthKind::Token { .. } => ClientAuth::ApiToken(secret),
+            AuthKind::Basic { username, .. } => ClientAuth::Basic {
+                username: username.clone(),
+                password: secret,
+            },
+            AuthKind::Header { header, .. } => ClientAuth::Header {
+                name: header.clone(),
+                value: secret,
+            },
             AuthKind::OAuth {
                 client_id,
-                secret_alias,
                 token_url,
+                secret_alias,
+                options,
             } => {
-                let entry =
-                    Entry::new(SERVICE, secret_alias).and_then(|entry| entry.get_password());
-
-                match entry {
-                    Ok(pwd) => {
-                        let cfg = OAuth2Config::builder()
-                            .client_id(client_id)
-                            .client_secret(pwd)
-                            .token_url(token_url)
-                            .build();
-
-                        match Auth::oauth(cfg) {
-                            Ok(oauth) => (ConnectorStatus::Connected, oauth),
-                            Err(_) => (
-                                ConnectorStatus::Custom(format!(
-                                    "Failed to initialize OAuth2 for alias {}",
-                                    secret_alias
-                                )),
-                                Auth::NoAuth,
-                            ),
-                        }
+                let credential = match options.client_auth {
+                    OAuthClientAuth::ClientSecret => ClientCredential::Secret(secret),
+                    OAuthClientAuth::PrivateKeyJwt => ClientCredential::PrivateKeyJwt {
+                        key: secret,
+                        key_id: options.key_id.clone(),
+                    },
+                };
+                let settings = OAuth2Settings {
+                    client_id: client_id.clone(),
+                    token_url: token_url.clone(),
+                    credential,
+                    scopes: options.scopes.clone(),
+                    audience: options.audience.clone(),
+                };
+                match OAuth2::new(settings, http.clone()) {
+                    Ok(oauth) => ClientAuth::OAuth2(oauth),
+                    Err(err) => {
+                        return (
+                            ConnectorStatus::Custom(format!(
+                                "Failed to initialize OAuth2 for alias {}: {}",
+                                secret_alias, err
+                            )),
+                            ClientAuth::NoAuth,
+                        )
                     }
-                    Err(_err) => (
-                        ConnectorStatus::Custom(format!(
-                            "Secret not found for alias {}",
-                            secret_alias
-                        )),
-                        Auth::NoAuth,
-                    ),
                 }
             }
-        }
+        };
+        (ConnectorStatus::Unknown, auth)
+    }
+
+    pub async fn init_connector(cfg: ConnectorConfig) -> Connector {
+        let http = match transport::http_client(cfg.transport()) {
+            Ok(http) => http,
+            Err(err) => {
+                let status =
+                    ConnectorStatus::Custom(format!("Invalid transport settings: {:#}", err));
+                return Connector::new(cfg, Client::new(), ClientAuth::NoAuth, status);
+            }
+        };
+        let (status, auth) = Self::auth(&cfg, &http).await;
+        Connector::new(cfg, http, auth, status)
+    }
+
+    /// Secrets can come from external commands, so connectors are initialized concurrently.
+    pub async fn init_connectors(cfgs: Vec<ConnectorConfig>) -> Vec<Connector> {
+        futures::future::join_all(cfgs.into_iter().map(App::init_connector)).await
     }
 
-    fn init_connector(cfg: ConnectorConfig) -> Connector {
-        let (status, auth) = Self::auth(&cfg);
-        let client = EdcConnectorClient::builder()
-            .management_url(cfg.address())
-            .version(cfg.version().clone().into())
-            .with_auth(auth)
-            .maybe_participant_context(cfg.participant_context_id())
-            .build()
-            .unwrap();
-        Connector::new(cfg, client, status)
+    fn state_history(persist: bool, name: &str) -> StateHistory {
+        if persist {
+            StateHistory::persistent(name)
+        } else {
+            StateHistory::default()
+        }
     }
 
-    pub fn init_with_connectors(connectors: Vec<Connector>) -> App {
-        let connectors = ConnectorsComponent::new(connectors);
+    pub fn init_with_connectors(
+        connectors: Vec<Connector>,
+        persist_history: bool,
+        config_path: Option<PathBuf>,
+    ) -> App {
+        let connectors = ConnectorsComponent::new(connectors).on_health_check(Self::check_health);
+        let connectors = match config_path {
+            Some(path) => {
+                let remove_path = path.clone();
+                connectors
+                    .on_save(move |previous, connector| {
+                        Self::save_connector(path.clone(), previous, connector)
+                    })
+                    .on_remove(move |connector| {
+                        Self::remove_connector(remove_path.clone(), connector)
+                    })
+            }
+            None => connectors,
+        };
 
         let sheet = connectors.info_sheet().merge(Self::info_sheet());
 
         App {
             connectors,
+            dashboard: DashboardComponent::default().on_fetch(Self::fetch_dashboard),
             policies: PolicyDefinitionsComponent::default()
                 .on_fetch(Self::fetch_policies)
-                .on_single_fetch(Self::identity),
+                .on_single_fetch(Self::identity)
+                .with_copy(ManifestKind::Policy),
             assets: AssetsComponent::default()
                 .on_fetch(Self::fetch_assets)
-                .on_single_fetch(Self::identity),
+                .on_single_fetch(Self::identity)
+                .with_copy(ManifestKind::Asset),
             contract_definitions: ContractDefinitionsComponent::default()
                 .on_fetch(Self::fetch_contract_definitions)
-                .on_single_fetch(Self::identity),
+                .on_single_fetch(Self::identity)
+                .with_copy(ManifestKind::ContractDefinition),
             contract_negotiations: ContractNegotiationsComponent::default()
                 .on_fetch(Self::fetch_contract_negotiations)
-                .on_single_fetch(Self::identity),
+                .on_single_fetch(Self::identity)
+                .on_follow(Self::follow_contract_negotiation)
+                .with_history(Self::state_history(
+                    persist_history,
+                    "contract_negotiations",
+                )),
             contract_agreements: ContractAgreementsComponent::default()
                 .on_fetch(Self::fetch_contract_agreements)
                 .on_single_fetch(Self::identity),
             transfer_processes: TransferProcessesComponent::default()
                 .on_fetch(Self::fetch_transfer_processes)
-                .on_single_fetch(Self::identity),
+                .on_single_fetch(Self::identity)
+                .on_follow(Self::follow_transfer_process)
+                .with_history(Self::state_history(persist_history, "transfer_processes")),
             edrs: EdrsComponent::default()
                 .on_fetch(Self::fetch_edrs)
                 .on_single_fetch(Self::single_edr),
             dataplanes: DataPlanesComponent::default()
                 .on_fetch(Self::fetch_dataplanes)
                 .on_single_fetch(Self::identity),
+            participant_contexts: ParticipantContextsComponent::new(
+                ParticipantContextResources::default()
+                    .on_fetch(Self::fetch_participant_contexts)
+                    .on_single_fetch(Self::identity),
+            )
+            .on_create(Self::create_participant_context)
+            .on_delete(Self::delete_participant_context),
+            requests: RequestsComponent::default()
+                .on_fetch(Self::fetch_requests)
+                .on_single_fetch(Self::identity),
+            notifications: NotificationsComponent::default(),
+            apply: ApplyComponent::default()
+                .on_plan(Self::plan_apply)
+                .on_apply(Self::apply_plan),
+            compare: CompareComponent::default().on_compare(Self::compare),
             launch_bar: LaunchBar::default(),
             launch_bar_visible: false,
             focus: AppFocus::ConnectorList,
             footer: Footer::default(),
             header: HeaderComponent::with_sheet(sheet),
+            credentials: CredentialsComponent::default(),
+            copy: CopyComponent::default()
+                .on_plan(Self::plan_copy)
+                .on_copy(Self::apply_plan),
+            config_watcher: None,
+            config_error: None,
+            error: None,
         }
     }
 
-    pub fn init(cfg: Config) -> App {
-        let connectors = cfg
-            .connectors
-            .into_iter()
-            .map(App::init_connector)
-            .collect();
+    pub async fn init(path: PathBuf) -> App {
+        let mut app = match Config::parse(&path) {
+            Ok(cfg) => {
+                let connectors = Self::init_connectors(cfg.connectors).await;
+
+                Self::init_with_connectors(
+                    connectors,
+                    cfg.persist_state_history,
+                    Some(path.clone()),
+                )
+            }
+            Err(err) => {
+                let mut app = Self::init_with_connectors(vec![], false, Some(path.clone()));
+                app.config_error = Some(err);
+                app
+            }
+        };
 
-        Self::init_with_connectors(connectors)
+        match ConfigWatcher::new(&path) {
+            Ok(watcher) => app.config_watcher = Some(watcher),
+            Err(err) => tracing::warn!("Failed to watch config file {}: {}", path.display(), err),
+        }
+        app
+    }
+
+    fn watch_config(&self) -> ComponentReturn<AppMsg> {
+        match self.config_watcher.clone() {
+            Some(watcher) => ComponentReturn::cmd(
+                async move {
+                    let connectors = match watcher.changed().await {
+                        Ok(cfg) => Ok(Self::init_connectors(cfg.connectors).await),
+                        Err(err) => Err(err),
+                    };
+                    Ok(vec![AppMsg::ConfigReloaded(connectors).into()])
+                }
+                .boxed(),
+            ),
+            None => ComponentReturn::empty(),
+        }
+    }
+
+    async fn reload_config(
+        &mut self,
+        connectors: Result<Vec<Connector>, ConfigError>,
+    ) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        let mut ret = self.watch_config();
+        match connectors {
+            Ok(connectors) => {
+                self.config_error = None;
+                let update = Self::forward_update(
+                    &mut self.connectors,
+                    ConnectorsMsg::SetConnectors(connectors).into(),
+                    AppMsg::ConnectorsMsg,
+                )
+                .await?;
+                ret.cmds.extend(update.cmds);
+                self.change_sheet()?;
+                ret.actions.push(Action::Notification(Notification::info(
+                    "Configuration reloaded".to_string(),
+                )));
+            }
+            Err(err) => self.config_error = Some(err),
+        }
+        Ok(ret)
     }
 
     pub fn info_sheet() -> InfoSheet {
@@ -182,12 +353,26 @@ impl App {
         &mut self,
         noty: Notification,
     ) -> anyhow::Result<ComponentReturn<AppMsg>> {
-        let timeout = noty.timeout();
-        self.footer.show_notification(noty);
+        let noty = noty.with_origin(format!("{:?}", self.header.selected_menu()));
+        self.notifications.record(noty.clone());
+
+        Ok(Self::schedule_clear(self.footer.show_notification(noty)))
+    }
+
+    fn schedule_clear(timeout: Option<u64>) -> ComponentReturn<AppMsg> {
+        match timeout {
+            Some(timeout) => ComponentReturn::cmd(Self::clear_notification_cmd(timeout).boxed()),
+            None => ComponentReturn::empty(),
+        }
+    }
 
-        Ok(ComponentReturn::cmd(
-            Self::clear_notification_cmd(timeout).boxed(),
-        ))
+    /// Violations reported by the connector are listed in a popup, the footer only gets a summary.
+    fn show_error(&mut self, error: ApiError) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        let summary = error.summary();
+        if !error.violations().is_empty() {
+            self.error = Some(error);
+        }
+        self.show_notification(Notification::error(summary))
     }
 
     async fn clear_notification_cmd(timeout: u64) -> anyhow::Result<Vec<ComponentMsg<AppMsg>>> {
@@ -196,13 +381,14 @@ impl App {
     }
 
     pub fn clear_notification(&mut self) -> anyhow::Result<ComponentReturn<AppMsg>> {
-        self.footer.clear_notification();
-        Ok(ComponentReturn::empty())
+        let next = self.footer.clear_notification();
+        Ok(Self::schedule_clear(next))
     }
 
     pub fn change_sheet(&mut self) -> anyhow::Result<ComponentReturn<AppMsg>> {
         let component_sheet = match self.header.selected_menu() {
-            Menu::Connectors => InfoSheet::default(),
+            Menu::Connectors => self.connectors.key_bindings(),
+            Menu::Dashboard => self.dashboard.info_sheet(),
             Menu::Assets => self.assets.info_sheet(),
             Menu::Policies => self.policies.info_sheet(),
             Menu::ContractDefinitions => self.contract_definitions.info_sheet(),
@@ -211,6 +397,11 @@ impl App {
             Menu::TransferProcesses => self.transfer_processes.info_sheet(),
             Menu::Edrs => self.edrs.info_sheet(),
             Menu::DataPlanes => self.dataplanes.info_sheet(),
+            Menu::ParticipantContexts => self.participant_contexts.info_sheet(),
+            Menu::Requests => self.requests.info_sheet(),
+            Menu::Notifications => self.notifications.info_sheet(),
+            Menu::Apply => self.apply.info_sheet(),
+            Menu::Compare => self.compare.info_sheet(),
         };
 
         self.header.update_sheet(
@@ -232,6 +423,10 @@ impl App {
                 self.focus = AppFocus::ConnectorList;
                 Ok(ComponentReturn::empty())
             }
+            (Menu::Dashboard, Some(connector)) => {
+                self.focus = AppFocus::Dashboard;
+                Self::forward_init(&mut self.dashboard, connector.clone(), AppMsg::Dashboard).await
+            }
             (Menu::Assets, Some(connector)) => {
                 self.focus = AppFocus::Assets;
                 Self::forward_init(&mut self.assets, connector.clone(), AppMsg::AssetsMsg).await
@@ -285,9 +480,132 @@ impl App {
                 Self::forward_init(&mut self.dataplanes, connector.clone(), AppMsg::DataPlanes)
                     .await
             }
+            (Menu::ParticipantContexts, Some(connector)) => {
+                self.focus = AppFocus::ParticipantContexts;
+                Self::forward_init(
+                    &mut self.participant_contexts,
+                    connector.clone(),
+                    AppMsg::ParticipantContexts,
+                )
+                .await
+            }
+            (Menu::Requests, Some(connector)) => {
+                self.focus = AppFocus::Requests;
+                Self::forward_init(&mut self.requests, connector.clone(), AppMsg::Requests).await
+            }
+            (Menu::Apply, Some(connector)) => {
+                self.focus = AppFocus::Apply;
+                Self::forward_init(&mut self.apply, connector.clone(), AppMsg::Apply).await
+            }
+            (Menu::Compare, Some(connector)) => {
+                self.focus = AppFocus::Compare;
+                let props = (connector.clone(), self.connectors.connectors());
+                Self::forward_init(&mut self.compare, props, AppMsg::Compare).await
+            }
+            (Menu::Notifications, _) => {
+                self.focus = AppFocus::Notifications;
+                Self::forward_init(&mut self.notifications, (), AppMsg::Notifications).await
+            }
             (_, None) => Ok(ComponentReturn::empty()),
         }
     }
+
+    pub fn set_participant_context(
+        &mut self,
+        ctx: Option<String>,
+    ) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        let supported = self
+            .connectors
+            .selected()
+            .is_some_and(Connector::supports_participant_contexts);
+        if !supported {
+            return Ok(ComponentReturn::action(Action::Notification(
+                Notification::error(String::from("Participant contexts require a V4 connector")),
+            )));
+        }
+        let msg = match ctx.as_ref() {
+            Some(ctx) => format!("Using participant context {}", ctx),
+            None => String::from("Not using any participant context"),
+        };
+        self.connectors.set_participant_context(ctx);
+        self.change_sheet()?;
+        Ok(ComponentReturn::action(Action::Notification(
+            Notification::info(msg),
+        )))
+    }
+
+    pub async fn request_credentials(
+        &mut self,
+        connector: Connector,
+    ) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        if self.credentials.is_visible() || connector.config().auth().secret_alias().is_none() {
+            return Ok(ComponentReturn::empty());
+        }
+        Self::forward_init(&mut self.credentials, connector, AppMsg::Credentials).await
+    }
+
+    pub async fn set_credentials(
+        &mut self,
+        connector: Connector,
+        credentials: Credentials,
+    ) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        let mut ret = ComponentReturn::empty();
+        let alias = connector.config().auth().secret_alias().unwrap_or_default();
+
+        if credentials.save && connector.config().secret_source().is_keyring() {
+            if let Err(err) = secrets::store(alias, &credentials.secret) {
+                ret.actions
+                    .push(Action::Notification(Notification::error(err.to_string())));
+            }
+        }
+
+        let (status, auth) =
+            Self::auth_with_secret(connector.config(), connector.http(), credentials.secret);
+        let connector = connector.with_auth(auth, status);
+        let name = connector.config().name().to_string();
+        ret.cmds.extend(
+            self.connectors
+                .replace_connector(connector)
+                .map(AppMsg::ConnectorsMsg)
+                .cmds,
+        );
+
+        // Reload the current view so that it picks up the new credentials.
+        let selected = self.connectors.selected().map(|c| c.config().name());
+        if selected == Some(name.as_str()) {
+            let nav = self.header.selected_menu().clone().into();
+            let routed = self.handle_routing(nav).await?;
+            ret.msgs.extend(routed.msgs);
+            ret.cmds.extend(routed.cmds);
+            ret.actions.extend(routed.actions);
+        }
+        Ok(ret)
+    }
+
+    pub async fn handle_drill_down(
+        &mut self,
+        nav: Nav,
+        query: Query,
+    ) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        match nav {
+            Nav::AssetsList => self.assets.set_query(query),
+            Nav::PoliciesList => self.policies.set_query(query),
+            Nav::ContractDefinitionsList => self.contract_definitions.set_query(query),
+            Nav::ContractNegotiations => self.contract_negotiations.set_query(query),
+            Nav::ContractAgreements => self.contract_agreements.set_query(query),
+            Nav::TransferProcesses => self.transfer_processes.set_query(query),
+            Nav::Edrs => self.edrs.set_query(query),
+            Nav::ConnectorsList
+            | Nav::Dashboard
+            | Nav::DataPlanes
+            | Nav::ParticipantContexts
+            | Nav::Requests
+            | Nav::Notifications
+            | Nav::Apply
+            | Nav::Compare => {}
+        }
+        self.handle_routing(nav).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -295,6 +613,12 @@ impl Component for App {
     type Msg = AppMsg;
     type Props = ();
 
+    async fn init(&mut self, _props: Self::Props) -> anyhow::Result<ComponentReturn<AppMsg>> {
+        let mut ret = Self::forward_init(&mut self.connectors, (), AppMsg::ConnectorsMsg).await?;
+        ret.cmds.extend(self.watch_config().cmds);
+        Ok(ret)
+    }
+
     fn view(&mut self, f: &mut Frame, rect: Rect) {
         let main = self.main_layout(rect);
 
@@ -303,6 +627,7 @@ impl Component for App {
 
         match self.header.selected_menu() {
             Menu::Connectors => self.connectors.view(f, main[2]),
+            Menu::Dashboard => self.dashboard.view(f, main[2]),
             Menu::Assets => self.assets.view(f, main[2]),
             Menu::Policies => self.policies.view(f, main[2]),
             Menu::ContractDefinitions => self.contract_definitions.view(f, main[2]),
@@ -311,9 +636,29 @@ impl Component for App {
             Menu::TransferProcesses => self.transfer_processes.view(f, main[2]),
             Menu::Edrs => self.edrs.view(f, main[2]),
             Menu::DataPlanes => self.dataplanes.view(f, main[2]),
+            Menu::ParticipantContexts => self.participant_contexts.view(f, main[2]),
+            Menu::Requests => self.requests.view(f, main[2]),
+            Menu::Notifications => self.notifications.view(f, main[2]),
+            Menu::Apply => self.apply.view(f, main[2]),
+            Menu::Compare => self.compare.view(f, main[2]),
         }
 
         self.footer.view(f, main[3]);
+        self.credentials.view(f, rect);
+        self.copy.view(f, rect);
+
+        if let Some(error) = self.config_error.clone() {
+            let props = ConfigErrorProps {
+                path: self
+                    .config_watcher
+                    .as_ref()
+                    .map(|watcher| watcher.path().to_path_buf()),
+                error,
+            };
+            ConfigErrorComponent::default().view(&props, f, rect);
+        } else if let Some(error) = self.error.as_ref() {
+            ApiErrorComponent::default().view(error, f, rect);
+        }
     }
 
     async fn update(
@@ -343,6 +688,9 @@ impl Component for App {
             AppMsg::LaunchBarMsg(m) => {
                 Self::forward_update(&mut self.launch_bar, m.into(), AppMsg::LaunchBarMsg).await
             }
+            AppMsg::Dashboard(m) => {
+                Self::forward_update(&mut self.dashboard, m.into(), AppMsg::Dashboard).await
+            }
             AppMsg::AssetsMsg(m) => {
                 Self::forward_update(&mut self.assets, m.into(), AppMsg::AssetsMsg).await
             }
@@ -385,11 +733,55 @@ impl Component for App {
             AppMsg::DataPlanes(m) => {
                 Self::forward_update(&mut self.dataplanes, m.into(), AppMsg::DataPlanes).await
             }
+            AppMsg::ParticipantContexts(m) => {
+                Self::forward_update(
+                    &mut self.participant_contexts,
+                    m.into(),
+                    AppMsg::ParticipantContexts,
+                )
+                .await
+            }
+            AppMsg::Requests(m) => {
+                Self::forward_update(&mut self.requests, m.into(), AppMsg::Requests).await
+            }
+            AppMsg::Notifications(m) => {
+                Self::forward_update(&mut self.notifications, m.into(), AppMsg::Notifications).await
+            }
+            AppMsg::Apply(m) => {
+                Self::forward_update(&mut self.apply, m.into(), AppMsg::Apply).await
+            }
+            AppMsg::Compare(m) => {
+                Self::forward_update(&mut self.compare, m.into(), AppMsg::Compare).await
+            }
+            AppMsg::SetParticipantContext(ctx) => self.set_participant_context(ctx),
+            AppMsg::Credentials(m) => {
+                Self::forward_update(&mut self.credentials, m.into(), AppMsg::Credentials).await
+            }
+            AppMsg::RequestCredentials(connector) => self.request_credentials(connector).await,
+            AppMsg::Copy(m) => Self::forward_update(&mut self.copy, m.into(), AppMsg::Copy).await,
+            AppMsg::StartCopy(request) => {
+                let connectors = self.connectors.connectors();
+                Self::forward_init(&mut self.copy, (request, connectors), AppMsg::Copy).await
+            }
+            AppMsg::SetCredentials(connector, credentials) => {
+                self.set_credentials(connector, credentials).await
+            }
             AppMsg::HeaderMsg(m) => {
                 Self::forward_update(&mut self.header, m.into(), AppMsg::HeaderMsg).await
             }
             AppMsg::RoutingMsg(nav) => self.handle_routing(nav).await,
+            AppMsg::DrillDown(nav, query) => self.handle_drill_down(nav, query).await,
             AppMsg::ChangeSheet => self.change_sheet(),
+            AppMsg::ConfigReloaded(connectors) => self.reload_config(connectors).await,
+            AppMsg::ShowError(error) => self.show_error(error),
+            AppMsg::DismissError => {
+                self.error = None;
+                Ok(ComponentReturn::empty())
+            }
+            AppMsg::DismissConfigError => {
+                self.config_error = None;
+                Ok(ComponentReturn::empty())
+            }
             AppMsg::NontificationMsg(NotificationMsg::Show(noty)) => self.show_notification(noty),
             AppMsg::NontificationMsg(NotificationMsg::Clear) => self.clear_notification(),
         }
@@ -399,6 +791,32 @@ impl Component for App {
         &mut self,
         evt: ComponentEvent,
     ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if self.config_error.is_some() {
+            if let ComponentEvent::Event(Event::Key(key)) = evt {
+                if key.kind == event::KeyEventKind::Press && key.code == KeyCode::Esc {
+                    return Ok(vec![AppMsg::DismissConfigError.into()]);
+                }
+            }
+            return Ok(vec![]);
+        }
+
+        if self.error.is_some() {
+            if let ComponentEvent::Event(Event::Key(key)) = evt {
+                if key.kind == event::KeyEventKind::Press && key.code == KeyCode::Esc {
+                    return Ok(vec![AppMsg::DismissError.into()]);
+                }
+            }
+            return Ok(vec![]);
+        }
+
+        if self.credentials.is_visible() {
+            return Self::forward_event(&mut self.credentials, evt, AppMsg::Credentials);
+        }
+
+        if self.copy.is_visible() {
+            return Self::forward_event(&mut self.copy, evt, AppMsg::Copy);
+        }
+
         let msg = match self.focus {
             AppFocus::ConnectorList => {
                 Self::forward_event(&mut self.connectors, evt.clone(), AppMsg::ConnectorsMsg)?
@@ -406,6 +824,9 @@ impl Component for App {
             AppFocus::LaunchBar => {
                 Self::forward_event(&mut self.launch_bar, evt.clone(), AppMsg::LaunchBarMsg)?
             }
+            AppFocus::Dashboard => {
+                Self::forward_event(&mut self.dashboard, evt.clone(), AppMsg::Dashboard)?
+            }
             AppFocus::Assets => {
                 Self::forward_event(&mut self.assets, evt.clone(), AppMsg::AssetsMsg)?
             }
@@ -436,6 +857,21 @@ impl Component for App {
             AppFocus::DataPlanes => {
                 Self::forward_event(&mut self.dataplanes, evt.clone(), AppMsg::DataPlanes)?
             }
+            AppFocus::ParticipantContexts => Self::forward_event(
+                &mut self.participant_contexts,
+                evt.clone(),
+                AppMsg::ParticipantContexts,
+            )?,
+            AppFocus::Requests => {
+                Self::forward_event(&mut self.requests, evt.clone(), AppMsg::Requests)?
+            }
+            AppFocus::Notifications => {
+                Self::forward_event(&mut self.notifications, evt.clone(), AppMsg::Notifications)?
+            }
+            AppFocus::Apply => Self::forward_event(&mut self.apply, evt.clone(), AppMsg::Apply)?,
+            AppFocus::Compare => {
+                Self::forward_event(&mut self.compare, evt.clone(), AppMsg::Compare)?
+            }
         };
 
         if !msg.is_empty() {
@@ -11,6 +11,18 @@ impl ActionHandler for App {
         match (&self.focus, action) {
             (AppFocus::LaunchBar, Action::Esc) => Ok(vec![AppMsg::HideLaunchBar.into()]),
             (_, Action::NavTo(nav)) => Ok(vec![AppMsg::RoutingMsg(nav).into()]),
+            (_, Action::DrillDown(nav, query)) => Ok(vec![AppMsg::DrillDown(nav, query).into()]),
+            (_, Action::SetParticipantContext(ctx)) => {
+                Ok(vec![AppMsg::SetParticipantContext(ctx).into()])
+            }
+            (_, Action::RequestCredentials(connector)) => {
+                Ok(vec![AppMsg::RequestCredentials(connector).into()])
+            }
+            (_, Action::SetCredentials(connector, credentials)) => {
+                Ok(vec![AppMsg::SetCredentials(connector, credentials).into()])
+            }
+            (_, Action::Copy(request)) => Ok(vec![AppMsg::StartCopy(request).into()]),
+            (_, Action::Error(error)) => Ok(vec![AppMsg::ShowError(error).into()]),
             (_, Action::ChangeSheet) => Ok(vec![AppMsg::ChangeSheet.into()]),
             (_, Action::Notification(noty)) => Ok(vec![AppMsg::NontificationMsg(
                 crate::components::NotificationMsg::Show(noty),
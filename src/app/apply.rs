@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use edc_connector_client::types::{
+    asset::Asset, contract_definition::ContractDefinition, policy::PolicyDefinition, query::Query,
+};
+
+use crate::types::{
+    api_error::ApiError,
+    connector::Connector,
+    manifest::{Current, Manifest, ManifestKind, Plan, PlanAction, PlanStep},
+};
+
+use super::{fetch::fetch_all, App};
+
+/// Result of a plan step, `error` is the summary of the failure if any.
+#[derive(Debug, Clone)]
+pub struct StepOutcome {
+    pub step: PlanStep,
+    pub error: Option<String>,
+}
+
+impl App {
+    pub async fn plan_apply(
+        connector: Connector,
+        path: PathBuf,
+        prune: bool,
+    ) -> anyhow::Result<Plan> {
+        let manifest = Manifest::load(&path)?;
+        let current = Self::fetch_current(&connector).await?;
+        Plan::new(&manifest, &current, prune)
+    }
+
+    /// All the policies, assets and contract definitions of the connector.
+    pub(super) async fn fetch_current(connector: &Connector) -> anyhow::Result<Current> {
+        let client = connector.client();
+        let (policies, assets, contract_definitions) = futures::try_join!(
+            fetch_all(Query::default(), PolicyDefinition::id, |q| async move {
+                Ok(client.policies().query(q).await?)
+            }),
+            fetch_all(Query::default(), Asset::id, |q| async move {
+                Ok(client.assets().query(q).await?)
+            }),
+            fetch_all(Query::default(), ContractDefinition::id, |q| async move {
+                Ok(client.contract_definitions().query(q).await?)
+            }),
+        )?;
+
+        Ok(Current {
+            policies,
+            assets,
+            contract_definitions,
+        })
+    }
+
+    /// Runs the steps in order, a failed step doesn't stop the following ones.
+    pub async fn apply_plan(connector: Connector, plan: Plan) -> Vec<StepOutcome> {
+        let mut outcomes = vec![];
+        for step in plan.steps() {
+            let error = Self::apply_step(&connector, step)
+                .await
+                .err()
+                .map(|err| ApiError::new(&err).summary());
+            outcomes.push(StepOutcome {
+                step: step.clone(),
+                error,
+            });
+        }
+        outcomes
+    }
+
+    pub(super) async fn apply_step(connector: &Connector, step: &PlanStep) -> anyhow::Result<()> {
+        let client = connector.client();
+        let id = step.id.as_str();
+        match (step.kind, step.action, step.body.as_ref()) {
+            (ManifestKind::Policy, PlanAction::Create, Some(body)) => {
+                client.policies().create(body).await?
+            }
+            (ManifestKind::Policy, PlanAction::Update, Some(body)) => {
+                client.policies().update(id, body).await?
+            }
+            (ManifestKind::Policy, PlanAction::Delete, _) => client.policies().delete(id).await?,
+            (ManifestKind::Asset, PlanAction::Create, Some(body)) => {
+                client.assets().create(body).await?
+            }
+            (ManifestKind::Asset, PlanAction::Update, Some(body)) => {
+                client.assets().update(id, body).await?
+            }
+            (ManifestKind::Asset, PlanAction::Delete, _) => client.assets().delete(id).await?,
+            (ManifestKind::ContractDefinition, PlanAction::Create, Some(body)) => {
+                client.contract_definitions().create(body).await?
+            }
+            (ManifestKind::ContractDefinition, PlanAction::Update, Some(body)) => {
+                client.contract_definitions().update(id, body).await?
+            }
+            (ManifestKind::ContractDefinition, PlanAction::Delete, _) => {
+                client.contract_definitions().delete(id).await?
+            }
+            (kind, action, None) => {
+                anyhow::bail!(
+                    "Cannot {} the {} {} without a body",
+                    action.label(),
+                    kind,
+                    id
+                )
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::Local;
+
+use crate::types::{
+    api_error::ApiError,
+    connector::Connector,
+    manifest::{BackupInfo, Manifest, ManifestKind, Plan, PlanAction},
+};
+
+use super::App;
+
+#[derive(Debug, Clone)]
+pub enum RestoreStatus {
+    Created,
+    Overwritten,
+    /// The resource exists with different values, the paths of the changed values.
+    Conflict(Vec<String>),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct RestoreOutcome {
+    pub kind: ManifestKind,
+    pub id: String,
+    pub status: RestoreStatus,
+}
+
+impl App {
+    /// Snapshot of the policies, assets and contract definitions of the connector.
+    pub async fn backup(connector: Connector) -> anyhow::Result<Manifest> {
+        let current = Self::fetch_current(&connector).await?;
+        let backup = BackupInfo {
+            connector: connector.config().name().to_string(),
+            management_url: connector.config().address().to_string(),
+            created_at: Local::now(),
+            policies: current.policies.len(),
+            assets: current.assets.len(),
+            contract_definitions: current.contract_definitions.len(),
+        };
+        Ok(Manifest {
+            backup: Some(backup),
+            policies: current.policies,
+            assets: current.assets,
+            contract_definitions: current.contract_definitions,
+        })
+    }
+
+    /// Recreates the resources of a backup missing from the connector. Resources that exist with
+    /// different values are reported as conflicts, unless `overwrite` is set. Nothing is deleted.
+    pub async fn restore(
+        connector: Connector,
+        manifest: Manifest,
+        overwrite: bool,
+    ) -> anyhow::Result<Vec<RestoreOutcome>> {
+        let current = Self::fetch_current(&connector).await?;
+        let plan = Plan::new(&manifest, &current, false)?;
+
+        let mut outcomes = vec![];
+        for step in plan.steps() {
+            let status = match step.action {
+                PlanAction::Update if !overwrite => RestoreStatus::Conflict(step.changes.clone()),
+                action => match Self::apply_step(&connector, step).await {
+                    Ok(()) if action == PlanAction::Create => RestoreStatus::Created,
+                    Ok(()) => RestoreStatus::Overwritten,
+                    Err(err) => RestoreStatus::Failed(ApiError::new(&err).summary()),
+                },
+            };
+            outcomes.push(RestoreOutcome {
+                kind: step.kind,
+                id: step.id.clone(),
+                status,
+            });
+        }
+        Ok(outcomes)
+    }
+}
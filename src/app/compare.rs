@@ -0,0 +1,58 @@
+use edc_connector_client::types::query::Query;
+use serde_json::Value;
+
+use crate::{
+    components::resources::DrawableResource,
+    types::{compare::Comparison, connector::Connector, manifest::ManifestKind},
+};
+
+use super::{fetch::fetch_all, App};
+
+impl App {
+    /// All the resources of a kind on both connectors, compared field by field.
+    pub async fn compare(
+        left: Connector,
+        right: Connector,
+        kind: ManifestKind,
+    ) -> anyhow::Result<Comparison> {
+        let (left, right) = futures::try_join!(
+            Self::fetch_compared(left, kind),
+            Self::fetch_compared(right, kind)
+        )?;
+        Ok(Comparison::new(left, right))
+    }
+
+    async fn fetch_compared(
+        connector: Connector,
+        kind: ManifestKind,
+    ) -> anyhow::Result<Vec<(String, Value)>> {
+        let query = Query::default();
+        Ok(match kind {
+            ManifestKind::Policy => to_json(
+                fetch_all(query, DrawableResource::id, |q| {
+                    Self::fetch_policies(connector.clone(), q)
+                })
+                .await?,
+            ),
+            ManifestKind::Asset => to_json(
+                fetch_all(query, DrawableResource::id, |q| {
+                    Self::fetch_assets(connector.clone(), q)
+                })
+                .await?,
+            ),
+            ManifestKind::ContractDefinition => to_json(
+                fetch_all(query, DrawableResource::id, |q| {
+                    Self::fetch_contract_definitions(connector.clone(), q)
+                })
+                .await?,
+            ),
+        })
+    }
+}
+
+fn to_json<T: DrawableResource>(entries: Vec<T>) -> Vec<(String, Value)> {
+    entries
+        .iter()
+        .map(|entry| (entry.id().to_string(), entry.to_json()))
+        .collect()
+}
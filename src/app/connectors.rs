@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::{edit, Config, ConnectorConfig},
+    types::connector::{Connector, NewConnector},
+};
+
+use super::{secrets, App};
+
+impl App {
+    pub async fn save_connector(
+        path: PathBuf,
+        previous: Option<String>,
+        connector: NewConnector,
+    ) -> anyhow::Result<Vec<Connector>> {
+        let configured = Self::configured_connectors(&path)?;
+        let name = connector.config.name().to_string();
+        let exists = configured
+            .iter()
+            .any(|c| c.name() == name && previous.as_deref() != Some(name.as_str()));
+        if exists {
+            anyhow::bail!("A connector named {} already exists", name);
+        }
+
+        let config = match configured
+            .iter()
+            .find(|c| Some(c.name()) == previous.as_deref())
+        {
+            Some(previous) => connector.config.with_settings_of(previous),
+            None => connector.config,
+        };
+
+        if let (Some(alias), Some(secret)) = (config.auth().secret_alias(), connector.secret) {
+            if !config.secret_source().is_keyring() {
+                anyhow::bail!(
+                    "The secret of {} is read from {}, it cannot be stored in the keyring",
+                    name,
+                    config.secret_source().name()
+                );
+            }
+            secrets::store(alias, &secret)?;
+        }
+
+        edit::save_connector(&path, previous.as_deref(), &config)?;
+        Self::load_connectors(&path).await
+    }
+
+    pub async fn remove_connector(
+        path: PathBuf,
+        connector: Connector,
+    ) -> anyhow::Result<Vec<Connector>> {
+        edit::remove_connector(&path, connector.config().name())?;
+        Self::load_connectors(&path).await
+    }
+
+    fn configured_connectors(path: &Path) -> anyhow::Result<Vec<ConnectorConfig>> {
+        if path.exists() {
+            Ok(Config::parse(path)?.connectors)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn load_connectors(path: &Path) -> anyhow::Result<Vec<Connector>> {
+        Ok(Self::init_connectors(Self::configured_connectors(path)?).await)
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use edc_connector_client::types::{asset::Asset, query::Query};
+
+use crate::types::{
+    connector::Connector,
+    copy::{ConflictMode, CopyOptions, CopyRequest},
+    manifest::{Current, Manifest, ManifestKind, Plan, PlanAction, PlanStep},
+    query::QueryValue,
+};
+
+use super::{fetch::fetch_all, App};
+
+/// The steps copying a resource to the target connector.
+#[derive(Debug, Clone)]
+pub struct CopyPlan {
+    pub plan: Plan,
+    /// Resources existing on the target with different values, left untouched.
+    pub skipped: Vec<PlanStep>,
+    /// Resources created under a new id on the target, as `(kind, id, new id)`.
+    pub renamed: Vec<(ManifestKind, String, String)>,
+    /// Resources already identical on the target.
+    pub unchanged: usize,
+}
+
+impl App {
+    pub async fn plan_copy(
+        target: Connector,
+        request: CopyRequest,
+        options: CopyOptions,
+    ) -> anyhow::Result<CopyPlan> {
+        let manifest = Self::copy_manifest(&request, options).await?;
+        let current = Self::fetch_current(&target).await?;
+        resolve_conflicts(manifest, &current, options.conflicts)
+    }
+
+    /// The requested resource, with the policies and assets it references when asked for.
+    async fn copy_manifest(
+        request: &CopyRequest,
+        options: CopyOptions,
+    ) -> anyhow::Result<Manifest> {
+        let client = request.source.client();
+        let mut manifest = Manifest::default();
+        match request.kind {
+            ManifestKind::Policy => manifest
+                .policies
+                .push(client.policies().get(&request.id).await?),
+            ManifestKind::Asset => manifest
+                .assets
+                .push(client.assets().get(&request.id).await?),
+            ManifestKind::ContractDefinition => {
+                let definition = client.contract_definitions().get(&request.id).await?;
+                if options.dependencies {
+                    let mut policy_ids = vec![
+                        definition.access_policy_id().to_string(),
+                        definition.contract_policy_id().to_string(),
+                    ];
+                    policy_ids.dedup();
+                    for id in policy_ids {
+                        manifest.policies.push(client.policies().get(&id).await?);
+                    }
+
+                    let selector = definition.assets_selector().iter().fold(
+                        Query::builder(),
+                        |query, criterion| {
+                            query.filter(
+                                criterion.operand_left(),
+                                criterion.operator(),
+                                QueryValue::new(criterion.operand_right().0.clone()),
+                            )
+                        },
+                    );
+                    manifest.assets = fetch_all(selector.build(), Asset::id, |q| async move {
+                        Ok(client.assets().query(q).await?)
+                    })
+                    .await?;
+                }
+                manifest.contract_definitions.push(definition);
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+/// Plans copying `manifest` onto `current`, handling the resources existing there with
+/// different values according to `mode`.
+fn resolve_conflicts(
+    mut manifest: Manifest,
+    current: &Current,
+    mode: ConflictMode,
+) -> anyhow::Result<CopyPlan> {
+    let total =
+        manifest.policies.len() + manifest.assets.len() + manifest.contract_definitions.len();
+    let mut plan = Plan::new(&manifest, current, false)?;
+
+    let conflicts = |plan: &Plan| {
+        plan.steps()
+            .iter()
+            .filter(|step| step.action == PlanAction::Update)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let mut skipped = vec![];
+    let mut renamed = vec![];
+    match mode {
+        ConflictMode::Overwrite => {}
+        ConflictMode::Skip => {
+            skipped = conflicts(&plan);
+            plan.retain(|step| step.action != PlanAction::Update);
+        }
+        ConflictMode::Rename => {
+            // Renaming a policy or an asset rewrites the contract definitions referencing it,
+            // which may turn identical ones into updates: rename until nothing is overwritten.
+            loop {
+                let steps = conflicts(&plan);
+                if steps.is_empty() {
+                    break;
+                }
+                for step in steps {
+                    let id = free_id(&manifest, current, step.kind, &step.id);
+                    manifest.rename(step.kind, &step.id, &id)?;
+                    renamed.push((step.kind, step.id, id));
+                }
+                plan = Plan::new(&manifest, current, false)?;
+            }
+        }
+    }
+
+    let unchanged = total - plan.steps().len() - skipped.len();
+    Ok(CopyPlan {
+        plan,
+        skipped,
+        renamed,
+        unchanged,
+    })
+}
+
+/// `<id>-copy`, or `<id>-copy-<n>` when taken on the target or by another copied resource.
+fn free_id(manifest: &Manifest, current: &Current, kind: ManifestKind, id: &str) -> String {
+    let taken = match kind {
+        ManifestKind::Policy => manifest
+            .policies
+            .iter()
+            .chain(current.policies.iter())
+            .map(|p| p.id())
+            .collect::<HashSet<_>>(),
+        ManifestKind::Asset => manifest
+            .assets
+            .iter()
+            .chain(current.assets.iter())
+            .map(|a| a.id())
+            .collect(),
+        ManifestKind::ContractDefinition => manifest
+            .contract_definitions
+            .iter()
+            .chain(current.contract_definitions.iter())
+            .map(|c| c.id())
+            .collect(),
+    };
+    (1..)
+        .map(|n| match n {
+            1 => format!("{}-copy", id),
+            n => format!("{}-copy-{}", id, n),
+        })
+        .find(|candidate| !taken.contains(candidate.as_str()))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use edc_connector_client::types::{
+        contract_definition::ContractDefinition,
+        policy::{Policy, PolicyDefinition},
+    };
+
+    use super::*;
+
+    fn policy(id: &str, assignee: &str) -> PolicyDefinition {
+        PolicyDefinition::builder()
+            .id(id)
+            .policy(Policy::builder().assignee(assignee).build())
+            .build()
+    }
+
+    fn contract_definition(id: &str, policy_id: &str) -> ContractDefinition {
+        ContractDefinition::builder()
+            .id(id)
+            .access_policy_id(policy_id)
+            .contract_policy_id(policy_id)
+            .build()
+    }
+
+    #[test]
+    fn rename_leaves_no_updates() {
+        let manifest = Manifest {
+            policies: vec![policy("p1", "alice")],
+            contract_definitions: vec![contract_definition("c1", "p1")],
+            ..Default::default()
+        };
+        // The policy differs on the target, the contract definition only once the policy is renamed.
+        let current = Current {
+            policies: vec![policy("p1", "bob")],
+            contract_definitions: vec![contract_definition("c1", "p1")],
+            ..Default::default()
+        };
+
+        let copy = resolve_conflicts(manifest, &current, ConflictMode::Rename).unwrap();
+
+        let steps = copy
+            .plan
+            .steps()
+            .iter()
+            .map(|step| (step.kind, step.action, step.id.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![
+                (ManifestKind::Policy, PlanAction::Create, "p1-copy"),
+                (
+                    ManifestKind::ContractDefinition,
+                    PlanAction::Create,
+                    "c1-copy"
+                ),
+            ]
+        );
+        let body = copy.plan.steps()[1].body.as_ref().unwrap();
+        assert_eq!(body["accessPolicyId"], "p1-copy");
+        assert_eq!(body["contractPolicyId"], "p1-copy");
+        assert_eq!(copy.renamed.len(), 2);
+        assert_eq!(copy.unchanged, 0);
+    }
+}
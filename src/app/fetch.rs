@@ -1,22 +1,182 @@
-use edc_connector_client::types::query::Query;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+};
+
+use edc_connector_client::types::query::{Query, SortOrder};
+use serde_json::Value;
 
 use crate::{
+    client::log,
     components::{
         agreements::ContractAgreementEntry,
         assets::AssetEntry,
         contract_definitions::ContractDefinitionEntry,
         contract_negotiations::ContractNegotiationEntry,
+        dashboard::{DashboardStats, StateCount},
         dataplanes::DataPlaneEntry,
         edrs::{EdrEntry, EdrMetadataEntry},
         policies::PolicyDefinitionEntry,
+        requests::RequestEntry,
+        resources::{follow::FollowState, DrawableResource},
         transfer_processes::TransferProcessEntry,
     },
-    types::connector::Connector,
+    types::{connector::Connector, query},
 };
 
 use super::App;
 
+const PAGE_SIZE: u32 = 100;
+
+/// Walks the pages of a query from its offset, stopping after a short page or one without new
+/// resources, as some APIs ignore the offset or repeat pages.
+#[derive(Debug, Clone)]
+pub struct Pager {
+    query: Query,
+    next: Option<u32>,
+    seen: HashSet<String>,
+}
+
+impl Pager {
+    pub fn new(query: Query) -> Pager {
+        Pager {
+            next: Some(query.offset()),
+            query,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// The query of the next page, `None` once the last one was fetched.
+    pub fn next_query(&self) -> Option<Query> {
+        self.next
+            .map(|offset| self.query.to_builder().offset(offset).build())
+    }
+
+    /// Records a fetched page, returning its resources not seen on the previous ones.
+    pub fn add<T>(&mut self, page: Vec<T>, id: impl Fn(&T) -> &str) -> Vec<T> {
+        let limit = self.query.limit();
+        let fetched = page.len() as u32;
+        let new = page
+            .into_iter()
+            .filter(|element| self.seen.insert(id(element).to_string()))
+            .collect::<Vec<_>>();
+        self.next = self
+            .next
+            .filter(|_| limit > 0 && fetched >= limit && !new.is_empty())
+            .and_then(|offset| offset.checked_add(limit));
+        new
+    }
+}
+
+/// Every page of the query, fetched with its own page size.
+pub async fn fetch_pages<T, F, Fut>(
+    query: Query,
+    id: impl Fn(&T) -> &str,
+    fetch_page: F,
+) -> anyhow::Result<Vec<T>>
+where
+    F: Fn(Query) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    let mut elements = vec![];
+    let mut pager = Pager::new(query);
+    while let Some(query) = pager.next_query() {
+        let page = fetch_page(query).await?;
+        elements.extend(pager.add(page, &id));
+    }
+    Ok(elements)
+}
+
+/// Every resource matching the query, fetched [`PAGE_SIZE`] at a time.
+pub async fn fetch_all<T, F, Fut>(
+    query: Query,
+    id: impl Fn(&T) -> &str,
+    fetch_page: F,
+) -> anyhow::Result<Vec<T>>
+where
+    F: Fn(Query) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    fetch_pages(query.to_builder().limit(PAGE_SIZE).build(), id, fetch_page).await
+}
+
+/// Filters, sorts and pages the elements of APIs returning everything at once.
+pub fn query_locally<T: DrawableResource>(query: &Query, elements: Vec<T>) -> Vec<T> {
+    let mut elements = elements
+        .into_iter()
+        .map(|element| (element.to_json(), element))
+        .filter(|(fields, _)| query::matches(query.filter_expression(), fields))
+        .collect::<Vec<_>>();
+
+    if let Some(sort) = query.sort() {
+        elements.sort_by(|(left, _), (right, _)| {
+            let ordering = query::compare_values(
+                left.get(sort.field()).unwrap_or(&Value::Null),
+                right.get(sort.field()).unwrap_or(&Value::Null),
+            );
+            match sort.order() {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+    }
+
+    elements
+        .into_iter()
+        .map(|(_, element)| element)
+        .skip(query.offset() as usize)
+        .take(query.limit() as usize)
+        .collect()
+}
+
+fn count_by_state<T: DrawableResource>(elements: &[T]) -> Vec<StateCount> {
+    let mut counts = HashMap::<String, u64>::new();
+    for element in elements {
+        let state = element.state().unwrap_or_else(|| String::from("UNKNOWN"));
+        *counts.entry(state).or_default() += 1;
+    }
+    let mut counts = counts
+        .into_iter()
+        .map(|(state, count)| StateCount::new(state, count))
+        .collect::<Vec<_>>();
+    counts.sort_by(|a, b| b.count().cmp(&a.count()).then(a.state().cmp(b.state())));
+    counts
+}
+
 impl App {
+    pub async fn fetch_dashboard(connector: Connector) -> anyhow::Result<DashboardStats> {
+        let (assets, policies, contract_definitions, contract_agreements, negotiations, transfers) =
+            futures::try_join!(
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_assets(connector.clone(), q)
+                }),
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_policies(connector.clone(), q)
+                }),
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_contract_definitions(connector.clone(), q)
+                }),
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_contract_agreements(connector.clone(), q)
+                }),
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_contract_negotiations(connector.clone(), q)
+                }),
+                fetch_all(Query::default(), DrawableResource::id, |q| {
+                    Self::fetch_transfer_processes(connector.clone(), q)
+                }),
+            )?;
+
+        Ok(DashboardStats {
+            assets: assets.len() as u64,
+            policies: policies.len() as u64,
+            contract_definitions: contract_definitions.len() as u64,
+            contract_agreements: contract_agreements.len() as u64,
+            contract_negotiations: count_by_state(&negotiations),
+            transfer_processes: count_by_state(&transfers),
+        })
+    }
+
     pub async fn fetch_assets(
         connector: Connector,
         query: Query,
@@ -100,18 +260,20 @@ impl App {
             .collect())
     }
 
+    // Data planes are not paged by the management API.
     pub async fn fetch_dataplanes(
         connector: Connector,
-        _query: Query,
+        query: Query,
     ) -> anyhow::Result<Vec<DataPlaneEntry>> {
-        Ok(connector
+        let data_planes = connector
             .client()
             .data_planes()
             .list()
             .await?
             .into_iter()
             .map(DataPlaneEntry::new)
-            .collect())
+            .collect();
+        Ok(query_locally(&query, data_planes))
     }
 
     pub async fn fetch_policies(
@@ -128,6 +290,42 @@ impl App {
             .collect())
     }
 
+    pub async fn follow_contract_negotiation(
+        connector: Connector,
+        entry: ContractNegotiationEntry,
+    ) -> anyhow::Result<FollowState> {
+        let negotiation = connector
+            .client()
+            .contract_negotiations()
+            .get(entry.id())
+            .await?;
+        Ok(ContractNegotiationEntry::new(negotiation).follow_state())
+    }
+
+    pub async fn follow_transfer_process(
+        connector: Connector,
+        entry: TransferProcessEntry,
+    ) -> anyhow::Result<FollowState> {
+        let transfer_process = connector
+            .client()
+            .transfer_processes()
+            .get(entry.id())
+            .await?;
+        Ok(TransferProcessEntry::new(transfer_process).follow_state())
+    }
+
+    pub async fn fetch_requests(
+        connector: Connector,
+        query: Query,
+    ) -> anyhow::Result<Vec<RequestEntry>> {
+        Ok(log::requests(connector.config().name())
+            .into_iter()
+            .skip(query.offset() as usize)
+            .take(query.limit() as usize)
+            .map(RequestEntry::new)
+            .collect())
+    }
+
     pub async fn identity<T>(_connector: Connector, entity: T) -> anyhow::Result<T> {
         Ok(entity)
     }
@@ -145,3 +343,50 @@ impl App {
             .map(Ok)?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn id(id: &String) -> &str {
+        id
+    }
+
+    #[test]
+    fn pager_stops() {
+        let query = || Query::builder().limit(2).build();
+        let cases: &[(&str, &[&[&str]], usize)] = &[
+            ("short page", &[&["a", "b"], &["c"]], 2),
+            ("empty page", &[&["a", "b"], &[]], 2),
+            ("repeated page", &[&["a", "b"], &["a", "b"]], 2),
+            ("full pages", &[&["a", "b"], &["c", "d"]], usize::MAX),
+        ];
+        for (name, pages, fetches) in cases {
+            let mut pager = Pager::new(query());
+            let mut count = 0;
+            for ids in pages.iter() {
+                assert!(pager.next_query().is_some(), "{}", name);
+                pager.add(page(ids), id);
+                count += 1;
+            }
+            if *fetches == usize::MAX {
+                assert_eq!(pager.next_query().unwrap().offset(), 4, "{}", name);
+            } else {
+                assert_eq!(count, *fetches, "{}", name);
+                assert!(pager.next_query().is_none(), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn pager_returns_new_elements() {
+        let mut pager = Pager::new(Query::builder().limit(2).build());
+        assert_eq!(pager.add(page(&["a", "b"]), id), vec!["a", "b"]);
+        assert_eq!(pager.add(page(&["b", "c"]), id), vec!["c"]);
+        assert_eq!(pager.next_query().unwrap().offset(), 4);
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+use edc_connector_client::{types::query::Query, Error};
+
+use crate::{
+    config::ConnectorApiVersion,
+    types::connector::{Connector, ConnectorStatus},
+};
+
+use super::App;
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl App {
+    pub async fn check_health(connector: Connector) -> Connector {
+        if connector.version().is_some() {
+            let status = Self::probe(&connector).await;
+            return connector.with_status(status);
+        }
+
+        // Participant contexts only exist on V4, and their paths are the same whatever the version.
+        if connector.participant_context().is_some() {
+            let candidate = connector.with_version(ConnectorApiVersion::V4);
+            let status = Self::probe(&candidate).await;
+            return candidate.with_status(status);
+        }
+
+        // Without a configured version, the first one that doesn't answer 404 wins.
+        let mut status = ConnectorStatus::Unknown;
+        for version in [ConnectorApiVersion::V3, ConnectorApiVersion::V4] {
+            let candidate = connector.with_version(version);
+            status = Self::probe(&candidate).await;
+            match status {
+                ConnectorStatus::Connected { .. } => return candidate.with_status(status),
+                ConnectorStatus::Failed { status: 404, .. } => continue,
+                _ => break,
+            }
+        }
+        connector.with_status(status)
+    }
+
+    async fn probe(connector: &Connector) -> ConnectorStatus {
+        let started = Instant::now();
+        let assets = connector.client().assets();
+        let probe = assets.query(Query::builder().limit(1).build());
+        let result = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, probe).await {
+            Ok(result) => result,
+            Err(_) => return ConnectorStatus::Unreachable(String::from("timed out")),
+        };
+        let latency = started.elapsed();
+
+        match result {
+            Ok(_) => ConnectorStatus::Connected { latency },
+            Err(Error::ManagementApi(err)) => match err.status_code.as_u16() {
+                status @ (401 | 403) => ConnectorStatus::Unauthorized { status, latency },
+                status => ConnectorStatus::Failed { status, latency },
+            },
+            Err(Error::Auth(err)) => ConnectorStatus::AuthFailed(err.to_string()),
+            Err(Error::Reqwest(err)) => ConnectorStatus::Unreachable(err.to_string()),
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub enum AppFocus {
     #[default]
     ConnectorList,
     LaunchBar,
+    Dashboard,
     Assets,
     Policies,
     ContractDefinitions,
@@ -11,4 +12,9 @@ pub enum AppFocus {
     TransferProcesses,
     Edrs,
     DataPlanes,
+    ParticipantContexts,
+    Requests,
+    Notifications,
+    Apply,
+    Compare,
 }
@@ -1,12 +1,24 @@
+use edc_connector_client::types::query::Query;
+
 use crate::{
     components::{
-        agreements::ContractAgreementsMsg, assets::AssetsMsg, connectors::msg::ConnectorsMsg,
+        agreements::ContractAgreementsMsg, apply::msg::ApplyMsg, assets::AssetsMsg,
+        compare::msg::CompareMsg, connectors::msg::ConnectorsMsg,
         contract_definitions::ContractDefinitionsMsg,
-        contract_negotiations::ContractNegotiationMsg, dataplanes::DataPlaneMsg, edrs::EdrsMsg,
-        header::msg::HeaderMsg, launch_bar::msg::LaunchBarMsg, policies::PoliciesMsg,
-        transfer_processes::TransferProcessMsg, NotificationMsg,
+        contract_negotiations::ContractNegotiationMsg, copy::msg::CopyMsg,
+        credentials::msg::CredentialsMsg, dashboard::msg::DashboardMsg, dataplanes::DataPlaneMsg,
+        edrs::EdrsMsg, header::msg::HeaderMsg, launch_bar::msg::LaunchBarMsg,
+        notifications::msg::NotificationsMsg, participants::msg::ParticipantContextsMsg,
+        policies::PoliciesMsg, requests::RequestsMsg, transfer_processes::TransferProcessMsg,
+        NotificationMsg,
+    },
+    config::ConfigError,
+    types::{
+        api_error::ApiError,
+        connector::{Connector, Credentials},
+        copy::CopyRequest,
+        nav::Nav,
     },
-    types::nav::Nav,
 };
 
 #[derive(Debug)]
@@ -15,6 +27,7 @@ pub enum AppMsg {
     ShowLaunchBar,
     HideLaunchBar,
     LaunchBarMsg(LaunchBarMsg),
+    Dashboard(DashboardMsg),
     AssetsMsg(AssetsMsg),
     PoliciesMsg(PoliciesMsg),
     ContractDefinitions(ContractDefinitionsMsg),
@@ -23,8 +36,24 @@ pub enum AppMsg {
     TransferProcesses(TransferProcessMsg),
     Edrs(EdrsMsg),
     DataPlanes(DataPlaneMsg),
+    ParticipantContexts(ParticipantContextsMsg),
+    Requests(RequestsMsg),
+    Notifications(NotificationsMsg),
+    Apply(ApplyMsg),
+    Compare(CompareMsg),
     HeaderMsg(HeaderMsg),
     RoutingMsg(Nav),
+    DrillDown(Nav, Query),
+    SetParticipantContext(Option<String>),
+    Credentials(CredentialsMsg),
+    RequestCredentials(Connector),
+    Copy(CopyMsg),
+    StartCopy(CopyRequest),
+    SetCredentials(Connector, Credentials),
     NontificationMsg(NotificationMsg),
     ChangeSheet,
+    ConfigReloaded(Result<Vec<Connector>, ConfigError>),
+    DismissConfigError,
+    ShowError(ApiError),
+    DismissError,
 }
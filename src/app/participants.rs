@@ -0,0 +1,63 @@
+use edc_connector_client::types::query::Query;
+use reqwest::Method;
+use serde_json::json;
+
+use crate::{
+    components::{participants::ParticipantContextEntry, resources::DrawableResource},
+    types::{
+        connector::Connector,
+        participant::{NewParticipantContext, ParticipantContext},
+    },
+};
+
+use super::{fetch::query_locally, App};
+
+// The client only supports creating participant contexts, so the admin API is called directly.
+impl App {
+    pub async fn fetch_participant_contexts(
+        connector: Connector,
+        query: Query,
+    ) -> anyhow::Result<Vec<ParticipantContextEntry>> {
+        let client = connector.client();
+        let request = client.admin_request(Method::GET, &["participants"]);
+        let contexts = client
+            .send(request)
+            .await?
+            .json::<Vec<ParticipantContext>>()
+            .await?
+            .into_iter()
+            .map(ParticipantContextEntry::new)
+            .collect();
+        Ok(query_locally(&query, contexts))
+    }
+
+    pub async fn create_participant_context(
+        connector: Connector,
+        ctx: NewParticipantContext,
+    ) -> anyhow::Result<()> {
+        let mut body = json!({
+            "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+            "@type": "ParticipantContext",
+            "identity": ctx.identity,
+        });
+        if let Some(id) = ctx.id {
+            body["@id"] = json!(id);
+        }
+        let client = connector.client();
+        let request = client
+            .admin_request(Method::POST, &["participants"])
+            .json(&body);
+        client.send(request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_participant_context(
+        connector: Connector,
+        entry: ParticipantContextEntry,
+    ) -> anyhow::Result<()> {
+        let client = connector.client();
+        let request = client.admin_request(Method::DELETE, &["participants", entry.id()]);
+        client.send(request).await?;
+        Ok(())
+    }
+}
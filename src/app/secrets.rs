@@ -0,0 +1,80 @@
+use std::{process::Stdio, time::Duration};
+
+use keyring::Entry;
+use tokio::process::Command;
+
+use crate::config::{ConnectorConfig, SecretSource};
+
+use super::SERVICE;
+
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Resolves the token or client secret of a connector from its configured source.
+/// Errors are short descriptions meant to be shown as connector status.
+pub async fn resolve(cfg: &ConnectorConfig) -> Result<Option<String>, String> {
+    let Some(alias) = cfg.auth().secret_alias() else {
+        return Ok(None);
+    };
+
+    let secret = match cfg.secret_source() {
+        SecretSource::Keyring => Entry::new(SERVICE, alias)
+            .and_then(|entry| entry.get_password())
+            .map_err(|err| match err {
+                keyring::Error::NoEntry => format!("keyring entry {} not found", alias),
+                err => format!("failed to read keyring entry {}: {}", alias, err),
+            })?,
+        SecretSource::Env { var } => {
+            std::env::var(var).map_err(|_| format!("environment variable {} is not set", var))?
+        }
+        SecretSource::File { path } => std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?,
+        SecretSource::Command { command } => run(command).await?,
+    };
+
+    let secret = secret.trim_end_matches(['\r', '\n']).to_string();
+    if secret.is_empty() {
+        Err(format!("{} secret is empty", cfg.secret_source().name()))
+    } else {
+        Ok(Some(secret))
+    }
+}
+
+pub fn store(alias: &str, secret: &str) -> anyhow::Result<()> {
+    Entry::new(SERVICE, alias)
+        .and_then(|entry| entry.set_password(secret))
+        .map_err(|err| anyhow::anyhow!("Failed to store secret {} in keyring: {}", alias, err))
+}
+
+async fn run(command: &str) -> Result<String, String> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+    cmd.stdin(Stdio::null()).kill_on_drop(true);
+
+    let output = tokio::time::timeout(COMMAND_TIMEOUT, cmd.output())
+        .await
+        .map_err(|_| {
+            format!(
+                "`{}` timed out after {}s",
+                command,
+                COMMAND_TIMEOUT.as_secs()
+            )
+        })?
+        .map_err(|err| format!("failed to run `{}`: {}", command, err))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(format!(
+            "`{}` failed: {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
@@ -0,0 +1,655 @@
+use std::{
+    io::{BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::Local;
+use clap::{Args, ValueEnum};
+use edc_connector_client::types::query::{Query, SortOrder};
+
+use crate::{
+    app::{backup::RestoreStatus, fetch::fetch_all, App},
+    components::{
+        agreements::ContractAgreementEntry,
+        assets::AssetEntry,
+        contract_definitions::ContractDefinitionEntry,
+        contract_negotiations::{self, ContractNegotiationEntry},
+        edrs::EdrEntry,
+        policies::PolicyDefinitionEntry,
+        resources::{
+            follow::{FollowState, FOLLOW_INTERVAL, FOLLOW_TIMEOUT},
+            DrawableResource, FieldValue,
+        },
+        transfer_processes::{self, TransferProcessEntry},
+    },
+    config::Config,
+    types::{
+        connector::{is_client_error, Connector},
+        manifest::{Manifest, Plan, PlanAction},
+        query::{encode_states, parse_expression, QueryValue, StateCodes},
+    },
+};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ResourceKind {
+    Assets,
+    Policies,
+    ContractDefinitions,
+    ContractNegotiations,
+    ContractAgreements,
+    TransferProcesses,
+    Edrs,
+    DataPlanes,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum WaitResource {
+    TransferProcess,
+    ContractNegotiation,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Ndjson,
+    Table,
+}
+
+#[derive(Debug, Args)]
+pub struct Target {
+    /// Name of the configured connector, can be omitted when only one is configured
+    #[arg(long)]
+    pub connector: Option<String>,
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Args)]
+pub struct QueryArgs {
+    /// Filter expression as in the TUI, e.g. `state = STARTED and assetId like 'foo%'`,
+    /// repeated filters are joined with `and`
+    #[arg(short, long)]
+    pub filter: Vec<String>,
+    /// Sort field, optionally followed by `:asc` or `:desc`
+    #[arg(short, long)]
+    pub sort: Option<String>,
+    /// Maximum number of results, all pages are fetched when omitted
+    #[arg(short, long)]
+    pub limit: Option<u32>,
+    #[arg(long, default_value_t = 0)]
+    pub offset: u32,
+}
+
+#[derive(Debug, Args)]
+pub struct WaitArgs {
+    /// State to wait for, can be repeated
+    #[arg(short, long = "state", required = true)]
+    pub states: Vec<String>,
+    /// Seconds to wait before giving up
+    #[arg(short, long, default_value_t = FOLLOW_TIMEOUT.as_secs())]
+    pub timeout: u64,
+    /// Seconds between two polls
+    #[arg(short, long, default_value_t = FOLLOW_INTERVAL.as_secs())]
+    pub interval: u64,
+    /// Name of the configured connector, can be omitted when only one is configured
+    #[arg(long)]
+    pub connector: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ApplyArgs {
+    /// Manifest file or directory of JSON/YAML manifests
+    #[arg(short = 'f', long = "file")]
+    pub path: PathBuf,
+    /// Delete the assets, policies and contract definitions missing from the manifests
+    #[arg(long)]
+    pub prune: bool,
+    /// Apply without asking for confirmation
+    #[arg(short, long)]
+    pub yes: bool,
+    /// Only print the plan
+    #[arg(long, conflicts_with = "yes")]
+    pub dry_run: bool,
+    /// Name of the configured connector, can be omitted when only one is configured
+    #[arg(long)]
+    pub connector: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct BackupArgs {
+    /// Directory, or JSON/YAML file, to write the backup to
+    pub path: PathBuf,
+    /// Name of the configured connector, can be omitted when only one is configured
+    #[arg(long)]
+    pub connector: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct RestoreArgs {
+    /// Backup directory or file
+    pub path: PathBuf,
+    /// Replace the resources that exist with different values instead of reporting a conflict
+    #[arg(long)]
+    pub overwrite: bool,
+    /// Name of the configured connector, can be omitted when only one is configured
+    #[arg(long)]
+    pub connector: Option<String>,
+}
+
+/// How a `wait` ended, each outcome has its own exit code.
+#[derive(Debug)]
+pub enum WaitOutcome {
+    Reached,
+    Failed,
+    TimedOut,
+    Ended,
+}
+
+impl WaitOutcome {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            WaitOutcome::Reached => 0,
+            WaitOutcome::Failed => 2,
+            WaitOutcome::TimedOut => 3,
+            WaitOutcome::Ended => 4,
+        }
+    }
+}
+
+impl ResourceKind {
+    fn state_codes(&self) -> StateCodes {
+        match self {
+            ResourceKind::ContractNegotiations => ContractNegotiationEntry::state_codes(),
+            ResourceKind::TransferProcesses => TransferProcessEntry::state_codes(),
+            _ => &[],
+        }
+    }
+}
+
+impl QueryArgs {
+    pub fn to_query(&self, state_codes: StateCodes) -> anyhow::Result<Query> {
+        let mut query = Query::builder().offset(self.offset);
+
+        if let Some(limit) = self.limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(sort) = self.sort.as_ref() {
+            let (field, order) = match sort.rsplit_once(':') {
+                Some((field, order)) => (field, parse_sort_order(order)?),
+                None => (sort.as_str(), SortOrder::Asc),
+            };
+            query = query.sort(field, order);
+        }
+
+        for filter in self.filter.iter() {
+            for condition in encode_states(parse_expression(filter)?, state_codes)? {
+                query = query.filter(
+                    &condition.field,
+                    &condition.operator,
+                    QueryValue::new(condition.value),
+                );
+            }
+        }
+
+        Ok(query.build())
+    }
+}
+
+fn parse_sort_order(order: &str) -> anyhow::Result<SortOrder> {
+    match order.to_lowercase().as_str() {
+        "asc" => Ok(SortOrder::Asc),
+        "desc" => Ok(SortOrder::Desc),
+        wrong => anyhow::bail!("Sort order {} not supported, expected asc or desc", wrong),
+    }
+}
+
+pub async fn list(
+    config: &Path,
+    resource: ResourceKind,
+    target: Target,
+    query: QueryArgs,
+) -> anyhow::Result<()> {
+    let connector = connect(config, target.connector.as_deref()).await?;
+    let paged = query.limit.is_some();
+    let query = query.to_query(resource.state_codes())?;
+    let output = target.output;
+
+    macro_rules! list {
+        ($fetch:path) => {{
+            let elements = if paged {
+                $fetch(connector.clone(), query).await?
+            } else {
+                fetch_all(query, DrawableResource::id, |q| {
+                    $fetch(connector.clone(), q)
+                })
+                .await?
+            };
+            print_all(&elements, output)
+        }};
+    }
+
+    match resource {
+        ResourceKind::Assets => list!(App::fetch_assets),
+        ResourceKind::Policies => list!(App::fetch_policies),
+        ResourceKind::ContractDefinitions => list!(App::fetch_contract_definitions),
+        ResourceKind::ContractNegotiations => list!(App::fetch_contract_negotiations),
+        ResourceKind::ContractAgreements => list!(App::fetch_contract_agreements),
+        ResourceKind::TransferProcesses => list!(App::fetch_transfer_processes),
+        ResourceKind::Edrs => list!(App::fetch_edrs),
+        ResourceKind::DataPlanes => list!(App::fetch_dataplanes),
+    }
+}
+
+pub async fn get(
+    config: &Path,
+    resource: ResourceKind,
+    id: &str,
+    data_address: bool,
+    target: Target,
+) -> anyhow::Result<()> {
+    if data_address && !matches!(resource, ResourceKind::Edrs) {
+        anyhow::bail!("--data-address only applies to edrs");
+    }
+    let connector = connect(config, target.connector.as_deref()).await?;
+    let client = connector.client();
+    let output = target.output;
+
+    match resource {
+        ResourceKind::Assets => print_one(&AssetEntry::new(client.assets().get(id).await?), output),
+        ResourceKind::Policies => print_one(
+            &PolicyDefinitionEntry::new(client.policies().get(id).await?),
+            output,
+        ),
+        ResourceKind::ContractDefinitions => print_one(
+            &ContractDefinitionEntry::new(client.contract_definitions().get(id).await?),
+            output,
+        ),
+        ResourceKind::ContractNegotiations => print_one(
+            &ContractNegotiationEntry::new(client.contract_negotiations().get(id).await?),
+            output,
+        ),
+        ResourceKind::ContractAgreements => print_one(
+            &ContractAgreementEntry::new(client.contract_agreements().get(id).await?),
+            output,
+        ),
+        ResourceKind::TransferProcesses => print_one(
+            &TransferProcessEntry::new(client.transfer_processes().get(id).await?),
+            output,
+        ),
+        ResourceKind::Edrs if data_address => print_one(
+            &EdrEntry::new(id.to_string(), client.edrs().get_data_address(id).await?),
+            output,
+        ),
+        ResourceKind::Edrs => {
+            let query = Query::builder()
+                .filter("transferProcessId", "=", id)
+                .build();
+            let edr = App::fetch_edrs(connector.clone(), query)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("EDR {} not found", id))?;
+            print_one(&edr, output)
+        }
+        ResourceKind::DataPlanes => {
+            let query = Query::builder().filter("id", "=", id).build();
+            let data_plane = App::fetch_dataplanes(connector.clone(), query)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("Data plane {} not found", id))?;
+            print_one(&data_plane, output)
+        }
+    }
+}
+
+/// Polls the resource printing each state change, until one of the requested states, a terminal one or the timeout.
+pub async fn wait(
+    config: &Path,
+    resource: WaitResource,
+    id: &str,
+    args: WaitArgs,
+) -> anyhow::Result<WaitOutcome> {
+    let known = match resource {
+        WaitResource::TransferProcess => transfer_processes::STATE_CODES,
+        WaitResource::ContractNegotiation => contract_negotiations::STATE_CODES,
+    };
+    let states = args
+        .states
+        .iter()
+        .map(|state| state.to_uppercase())
+        .collect::<Vec<_>>();
+    if let Some(unknown) = states
+        .iter()
+        .find(|state| !known.iter().any(|(name, _)| name == state))
+    {
+        anyhow::bail!(
+            "Unknown state {}, expected one of {}",
+            unknown,
+            known
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let connector = connect(config, args.connector.as_deref()).await?;
+    let interval = Duration::from_secs(args.interval);
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(args.timeout);
+    let mut last: Option<String> = None;
+
+    loop {
+        let polled = tokio::time::timeout_at(deadline, poll(&connector, resource, id)).await;
+        let Ok(current) = polled else {
+            break;
+        };
+        match current {
+            Ok(current) => {
+                if last.as_deref() != Some(current.state()) {
+                    println!("{} {}", Local::now().format("%H:%M:%S"), current.state());
+                    last = Some(current.state().to_string());
+                }
+
+                if states.iter().any(|state| state == current.state()) {
+                    return Ok(WaitOutcome::Reached);
+                }
+
+                if current.is_failed() {
+                    eprintln!("{} failed in state {}", id, current.state());
+                    return Ok(WaitOutcome::Failed);
+                }
+
+                if current.is_terminal() {
+                    eprintln!("{} ended in state {}", id, current.state());
+                    return Ok(WaitOutcome::Ended);
+                }
+            }
+            Err(err) if is_client_error(&err) => return Err(err),
+            // Gateway errors and dropped connections may be transient, the timeout bounds the wait.
+            Err(err) => eprintln!("{} {:#}", Local::now().format("%H:%M:%S"), err),
+        }
+
+        if tokio::time::Instant::now() + interval > deadline {
+            break;
+        }
+        tokio::time::sleep(interval).await;
+    }
+
+    eprintln!(
+        "Timed out after {}s waiting for {}, last state {}",
+        args.timeout,
+        id,
+        last.as_deref().unwrap_or("unknown")
+    );
+    Ok(WaitOutcome::TimedOut)
+}
+
+async fn poll(
+    connector: &Connector,
+    resource: WaitResource,
+    id: &str,
+) -> anyhow::Result<FollowState> {
+    let client = connector.client();
+    Ok(match resource {
+        WaitResource::TransferProcess => {
+            TransferProcessEntry::new(client.transfer_processes().get(id).await?).follow_state()
+        }
+        WaitResource::ContractNegotiation => {
+            ContractNegotiationEntry::new(client.contract_negotiations().get(id).await?)
+                .follow_state()
+        }
+    })
+}
+
+/// Prints the plan and executes it once confirmed, failing when any step failed.
+pub async fn apply(config: &Path, args: ApplyArgs) -> anyhow::Result<()> {
+    let connector = connect(config, args.connector.as_deref()).await?;
+    let plan = App::plan_apply(connector.clone(), args.path, args.prune).await?;
+
+    if plan.is_empty() {
+        println!(
+            "Nothing to apply, {} is up to date",
+            connector.config().name()
+        );
+        return Ok(());
+    }
+
+    print_plan(&plan);
+    if args.dry_run || !(args.yes || confirm(connector.config().name())?) {
+        return Ok(());
+    }
+
+    let outcomes = App::apply_plan(connector, plan).await;
+    let failed = outcomes.iter().filter(|o| o.error.is_some()).count();
+    for outcome in outcomes {
+        let step = &outcome.step;
+        match outcome.error {
+            None => println!("{}d {} {}", step.action.label(), step.kind, step.id),
+            Some(error) => eprintln!(
+                "failed to {} {} {}: {}",
+                step.action.label(),
+                step.kind,
+                step.id,
+                error
+            ),
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} step(s) failed", failed);
+    }
+    Ok(())
+}
+
+pub async fn backup(config: &Path, args: BackupArgs) -> anyhow::Result<()> {
+    let connector = connect(config, args.connector.as_deref()).await?;
+    let manifest = App::backup(connector.clone()).await?;
+    manifest.save(&args.path)?;
+    println!(
+        "Backed up {} policies, {} assets and {} contract definitions of {} to {}",
+        manifest.policies.len(),
+        manifest.assets.len(),
+        manifest.contract_definitions.len(),
+        connector.config().name(),
+        args.path.display()
+    );
+    Ok(())
+}
+
+pub async fn restore(config: &Path, args: RestoreArgs) -> anyhow::Result<()> {
+    let connector = connect(config, args.connector.as_deref()).await?;
+    let manifest = Manifest::load(&args.path)?;
+    if let Some(info) = manifest.backup.as_ref() {
+        println!(
+            "Restoring the backup of {} from {}",
+            info.connector,
+            info.created_at.format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+    let total =
+        manifest.policies.len() + manifest.assets.len() + manifest.contract_definitions.len();
+
+    let outcomes = App::restore(connector.clone(), manifest, args.overwrite).await?;
+    let (mut conflicts, mut failed) = (0, 0);
+    for outcome in outcomes.iter() {
+        match &outcome.status {
+            RestoreStatus::Created => println!("created {} {}", outcome.kind, outcome.id),
+            RestoreStatus::Overwritten => println!("overwrote {} {}", outcome.kind, outcome.id),
+            RestoreStatus::Conflict(changes) => {
+                conflicts += 1;
+                eprintln!(
+                    "conflict on {} {}, differs in {}",
+                    outcome.kind,
+                    outcome.id,
+                    changes.join(", ")
+                );
+            }
+            RestoreStatus::Failed(error) => {
+                failed += 1;
+                eprintln!(
+                    "failed to restore {} {}: {}",
+                    outcome.kind, outcome.id, error
+                );
+            }
+        }
+    }
+
+    println!(
+        "{} of {} resources already up to date on {}",
+        total - outcomes.len(),
+        total,
+        connector.config().name()
+    );
+    match (conflicts, failed) {
+        (0, 0) => Ok(()),
+        (0, failed) => anyhow::bail!("{} resource(s) failed to restore", failed),
+        (conflicts, failed) => anyhow::bail!(
+            "{} conflict(s) and {} failure(s), use --overwrite to replace conflicting resources",
+            conflicts,
+            failed
+        ),
+    }
+}
+
+fn print_plan(plan: &Plan) {
+    for step in plan.steps() {
+        let sign = match step.action {
+            PlanAction::Create => '+',
+            PlanAction::Update => '~',
+            PlanAction::Delete => '-',
+        };
+        println!("{} {} {}", sign, step.kind, step.id);
+        for change in step.changes.iter() {
+            println!("    {}", change);
+        }
+    }
+    println!("\nPlan: {}", plan.summary());
+}
+
+fn confirm(connector: &str) -> anyhow::Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("Not running in a terminal, use --yes to apply without confirmation");
+    }
+    print!("Apply to {}? [y/N] ", connector);
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Loads the connector from the config file, detecting its API version when not configured.
+async fn connect(config: &Path, name: Option<&str>) -> anyhow::Result<Connector> {
+    let connectors = Config::parse(config)?.connectors;
+    let cfg = match name {
+        Some(name) => connectors
+            .into_iter()
+            .find(|c| c.name() == name)
+            .ok_or_else(|| {
+                anyhow::anyhow!("Connector {} not found in {}", name, config.display())
+            })?,
+        None if connectors.len() == 1 => connectors.into_iter().next().unwrap(),
+        None => anyhow::bail!(
+            "Select a connector with --connector, configured: {}",
+            connectors
+                .iter()
+                .map(|c| c.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    let connector = App::init_connector(cfg).await;
+    if !connector.status().is_probeable() {
+        anyhow::bail!(
+            "Connector {}: {}",
+            connector.config().name(),
+            connector.status().label()
+        );
+    }
+
+    if connector.version().is_some() {
+        return Ok(connector);
+    }
+
+    let connector = App::check_health(connector).await;
+    match connector.version() {
+        Some(_) => Ok(connector),
+        None => anyhow::bail!(
+            "Connector {}: {}",
+            connector.config().name(),
+            connector.status().label()
+        ),
+    }
+}
+
+fn print_all<T: DrawableResource>(elements: &[T], output: OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Json => {
+            let values = elements.iter().map(T::to_json).collect::<Vec<_>>();
+            println!("{}", serde_json::to_string_pretty(&values)?);
+        }
+        OutputFormat::Ndjson => {
+            for element in elements {
+                println!("{}", serde_json::to_string(&element.to_json())?);
+            }
+        }
+        OutputFormat::Table => print_table(elements),
+    }
+    Ok(())
+}
+
+fn print_one<T: DrawableResource>(element: &T, output: OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&element.to_json())?),
+        OutputFormat::Ndjson => println!("{}", serde_json::to_string(&element.to_json())?),
+        OutputFormat::Table => print_table(std::slice::from_ref(element)),
+    }
+    Ok(())
+}
+
+/// Aligned columns named after the resource fields, JSON values are printed on a single line.
+fn print_table<T: DrawableResource>(elements: &[T]) {
+    let Some(first) = elements.first() else {
+        return;
+    };
+
+    let headers = first
+        .fields()
+        .iter()
+        .map(|field| field.name().to_uppercase())
+        .collect::<Vec<_>>();
+
+    let rows = elements
+        .iter()
+        .map(|element| {
+            element
+                .fields()
+                .iter()
+                .map(|field| match field.value() {
+                    FieldValue::Str(s) => s.clone(),
+                    json @ FieldValue::Json(_) => json.to_json().to_string(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = headers.iter().map(String::len).collect::<Vec<_>>();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(&headers).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
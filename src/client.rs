@@ -0,0 +1,430 @@
+use std::{
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use chrono::Local;
+
+use edc_connector_client::{
+    types::{
+        context::WithContextRef, data_address::DataAddress, edr::EndpointDataReferenceEntry,
+        query::Query,
+    },
+    EdcResult, Error, ManagementApiError, ManagementApiErrorDetailKind,
+};
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::config::{ConnectorApiVersion, ConnectorConfig};
+
+use self::{log::RecordedRequest, oauth::OAuth2};
+
+pub mod log;
+pub mod oauth;
+pub mod transport;
+
+/// Upper bound of the delay between retries, the backoff doubling on each attempt.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// How requests to the management API are authenticated.
+#[derive(Clone)]
+pub enum ClientAuth {
+    NoAuth,
+    ApiToken(String),
+    Basic { username: String, password: String },
+    Header { name: String, value: String },
+    OAuth2(OAuth2),
+}
+
+/// Client for the management API of a connector.
+///
+/// The upstream client doesn't allow to customize the HTTP requests, so the
+/// requests are sent from here reusing its types.
+#[derive(Clone)]
+pub struct ManagementClient(Arc<ClientInternal>);
+
+struct ClientInternal {
+    name: String,
+    http: Client,
+    management_url: String,
+    auth: ClientAuth,
+    headers: Vec<(String, String)>,
+    version: ConnectorApiVersion,
+    participant_context: Option<String>,
+    retries: u32,
+    retry_backoff: Duration,
+}
+
+pub struct ResourceApi<'a, T> {
+    client: &'a ManagementClient,
+    path: &'static str,
+    /// Policy definitions need the ODRL context on v3 and are updated by id.
+    policy: bool,
+    _marker: PhantomData<T>,
+}
+
+impl ManagementClient {
+    pub fn new(
+        http: Client,
+        config: &ConnectorConfig,
+        auth: ClientAuth,
+        version: ConnectorApiVersion,
+        participant_context: Option<String>,
+    ) -> ManagementClient {
+        ManagementClient(Arc::new(ClientInternal {
+            name: config.name().to_string(),
+            http,
+            management_url: config.address().trim_end_matches('/').to_string(),
+            auth,
+            headers: config
+                .headers()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            version,
+            participant_context,
+            retries: config.transport().retries,
+            retry_backoff: config.transport().retry_backoff(),
+        }))
+    }
+
+    pub fn assets(&self) -> ResourceApi<'_, edc_connector_client::types::asset::Asset> {
+        ResourceApi::new(self, "assets")
+    }
+
+    pub fn policies(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::policy::PolicyDefinition> {
+        ResourceApi::new(self, "policydefinitions").for_policies()
+    }
+
+    pub fn contract_definitions(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::contract_definition::ContractDefinition> {
+        ResourceApi::new(self, "contractdefinitions")
+    }
+
+    pub fn contract_negotiations(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::contract_negotiation::ContractNegotiation>
+    {
+        ResourceApi::new(self, "contractnegotiations")
+    }
+
+    pub fn contract_agreements(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::contract_agreement::ContractAgreement> {
+        ResourceApi::new(self, "contractagreements")
+    }
+
+    pub fn transfer_processes(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::transfer_process::TransferProcess> {
+        ResourceApi::new(self, "transferprocesses")
+    }
+
+    pub fn edrs(&self) -> ResourceApi<'_, EndpointDataReferenceEntry> {
+        ResourceApi::new(self, "edrs")
+    }
+
+    pub fn data_planes(
+        &self,
+    ) -> ResourceApi<'_, edc_connector_client::types::dataplane::DataPlaneInstance> {
+        ResourceApi::new(self, "dataplanes")
+    }
+
+    /// GET on a path of the management API, scoped to the participant context if any.
+    pub async fn get<R: DeserializeOwned>(&self, paths: &[&str]) -> EdcResult<R> {
+        let request = self.request(Method::GET, self.url(paths));
+        self.send(request).await?.json().await.map_err(Error::from)
+    }
+
+    /// POST with the JSON-LD context of the API version added to the body.
+    pub async fn post<B: Serialize, R: DeserializeOwned>(
+        &self,
+        paths: &[&str],
+        body: &B,
+    ) -> EdcResult<R> {
+        self.send_json(Method::POST, paths, body, false)
+            .await?
+            .json()
+            .await
+            .map_err(Error::from)
+    }
+
+    pub async fn delete(&self, paths: &[&str]) -> EdcResult<()> {
+        let request = self.request(Method::DELETE, self.url(paths));
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Sends a body with the JSON-LD context of the API version, policies also need the ODRL one on v3.
+    async fn send_json<B: Serialize>(
+        &self,
+        method: Method,
+        paths: &[&str],
+        body: &B,
+        odrl: bool,
+    ) -> EdcResult<Response> {
+        let request = self
+            .request(method, self.url(paths))
+            .json(&self.context_for(body, odrl));
+        self.send(request).await
+    }
+
+    /// Request to the admin API of a connector, outside of any participant context.
+    pub fn admin_request(&self, method: Method, paths: &[&str]) -> RequestBuilder {
+        let url = [self.0.management_url.as_str(), "v4alpha"]
+            .iter()
+            .chain(paths.iter())
+            .copied()
+            .collect::<Vec<_>>()
+            .join("/");
+        self.request(method, url)
+    }
+
+    /// Sends an authenticated request, recording it in the request log.
+    pub async fn send(&self, request: RequestBuilder) -> EdcResult<Response> {
+        let started = Instant::now();
+        let unauthenticated = request.try_clone();
+        let request = match self.authenticate(request).await {
+            Ok(request) => request.build()?,
+            Err(err) => {
+                if let Some(request) = unauthenticated.and_then(|r| r.build().ok()) {
+                    let mut recorded = self.recorded(&request);
+                    recorded.error = Some(err.to_string());
+                    recorded.latency = started.elapsed();
+                    log::record(recorded);
+                }
+                return Err(err);
+            }
+        };
+
+        let mut recorded = self.recorded(&request);
+        let result = match self.execute(request).await {
+            Ok(response) => {
+                let status = response.status();
+                response
+                    .bytes()
+                    .await
+                    .map(|body| (status, body))
+                    .map_err(Error::from)
+            }
+            Err(err) => Err(err),
+        };
+        recorded.latency = started.elapsed();
+
+        let (status, body) = match result {
+            Ok(response) => response,
+            Err(err) => {
+                recorded.error = Some(err.to_string());
+                log::record(recorded);
+                return Err(err);
+            }
+        };
+        recorded.status = Some(status.as_u16());
+        recorded.response = Some(log::redact_body(&body)).filter(|body| !body.is_empty());
+        log::record(recorded);
+
+        if status.is_success() {
+            let mut response = http::Response::new(body);
+            *response.status_mut() = status;
+            return Ok(response.into());
+        }
+
+        // Kept raw, the parsed details of the client drop the path of the violations.
+        Err(Error::ManagementApi(ManagementApiError {
+            status_code: status,
+            error_detail: ManagementApiErrorDetailKind::Raw(
+                String::from_utf8_lossy(&body).to_string(),
+            ),
+        }))
+    }
+
+    async fn authenticate(&self, request: RequestBuilder) -> EdcResult<RequestBuilder> {
+        Ok(match &self.0.auth {
+            ClientAuth::NoAuth => request,
+            ClientAuth::ApiToken(token) => request.header("X-Api-Key", token),
+            ClientAuth::Basic { username, password } => {
+                request.basic_auth(username, Some(password))
+            }
+            ClientAuth::Header { name, value } => request.header(name, value),
+            ClientAuth::OAuth2(oauth) => request.bearer_auth(oauth.token().await?),
+        })
+    }
+
+    fn recorded(&self, request: &Request) -> RecordedRequest {
+        // Configured headers often carry gateway keys, so their values are never shown either.
+        let sensitive = self
+            .0
+            .headers
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .chain(match &self.0.auth {
+                ClientAuth::Header { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        RecordedRequest {
+            id: String::new(),
+            connector: self.0.name.clone(),
+            timestamp: Local::now(),
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    let value = String::from_utf8_lossy(value.as_bytes());
+                    (
+                        name.to_string(),
+                        log::redact_header(name.as_str(), &value, &sensitive),
+                    )
+                })
+                .collect(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(log::redact_body),
+            status: None,
+            response: None,
+            error: None,
+            latency: Duration::ZERO,
+        }
+    }
+
+    /// Retries GET requests failing with connection errors, timeouts or gateway errors.
+    async fn execute(&self, request: Request) -> EdcResult<Response> {
+        let retries = if request.method() == Method::GET {
+            self.0.retries
+        } else {
+            0
+        };
+        let mut backoff = self.0.retry_backoff.min(MAX_BACKOFF);
+
+        for attempt in 1..=retries {
+            let Some(retried) = request.try_clone() else {
+                break;
+            };
+            let started = Instant::now();
+            let mut recorded = self.recorded(&retried);
+            let reason = match self.0.http.execute(retried).await {
+                Ok(response) if !is_transient(response.status()) => return Ok(response),
+                Ok(response) => {
+                    recorded.status = Some(response.status().as_u16());
+                    format!("status {}", response.status().as_u16())
+                }
+                Err(err) if !(err.is_connect() || err.is_timeout()) => return Err(err.into()),
+                Err(err) => err.to_string(),
+            };
+            recorded.latency = started.elapsed();
+            recorded.error = Some(format!(
+                "Attempt {} of {} failed ({}), retrying in {}ms",
+                attempt,
+                retries + 1,
+                reason,
+                backoff.as_millis()
+            ));
+            log::record(recorded);
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+
+        Ok(self.0.http.execute(request).await?)
+    }
+
+    fn request(&self, method: Method, url: String) -> RequestBuilder {
+        self.0.headers.iter().fold(
+            self.0.http.request(method, url),
+            |request, (name, value)| request.header(name, value),
+        )
+    }
+
+    fn url(&self, paths: &[&str]) -> String {
+        let version = self.0.version.api_version();
+        let base: Vec<&str> = match self.0.participant_context.as_ref() {
+            Some(ctx) => vec![&self.0.management_url, "v4alpha", "participants", ctx],
+            None => vec![&self.0.management_url, version.as_str()],
+        };
+        base.into_iter()
+            .chain(paths.iter().copied())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn context_for<'a, T>(&self, body: &'a T, odrl: bool) -> WithContextRef<'a, T> {
+        match self.0.version {
+            ConnectorApiVersion::V3 if odrl => WithContextRef::odrl_context(body),
+            ConnectorApiVersion::V3 => WithContextRef::default_context(body),
+            ConnectorApiVersion::V4 => WithContextRef::edc_v4_context(body),
+        }
+    }
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+impl<'a, T: DeserializeOwned> ResourceApi<'a, T> {
+    fn new(client: &'a ManagementClient, path: &'static str) -> Self {
+        Self {
+            client,
+            path,
+            policy: false,
+            _marker: PhantomData,
+        }
+    }
+
+    fn for_policies(mut self) -> Self {
+        self.policy = true;
+        self
+    }
+
+    pub async fn query(&self, query: Query) -> EdcResult<Vec<T>> {
+        self.client.post(&[self.path, "request"], &query).await
+    }
+
+    pub async fn get(&self, id: &str) -> EdcResult<T> {
+        self.client.get(&[self.path, id]).await
+    }
+
+    pub async fn list(&self) -> EdcResult<Vec<T>> {
+        self.client.get(&[self.path]).await
+    }
+
+    pub async fn create<B: Serialize>(&self, body: &B) -> EdcResult<()> {
+        self.client
+            .send_json(Method::POST, &[self.path], body, self.policy)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn update<B: Serialize>(&self, id: &str, body: &B) -> EdcResult<()> {
+        let paths: &[&str] = if self.policy {
+            &[self.path, id]
+        } else {
+            &[self.path]
+        };
+        self.client
+            .send_json(Method::PUT, paths, body, self.policy)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn delete(&self, id: &str) -> EdcResult<()> {
+        self.client.delete(&[self.path, id]).await
+    }
+}
+
+impl ResourceApi<'_, EndpointDataReferenceEntry> {
+    pub async fn get_data_address(&self, id: &str) -> EdcResult<DataAddress> {
+        self.client.get(&[self.path, id, "dataaddress"]).await
+    }
+}
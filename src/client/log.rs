@@ -0,0 +1,229 @@
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Local};
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+const CAPACITY: usize = 500;
+
+/// Headers whose values never leave the client unredacted.
+const SENSITIVE_HEADERS: [&str; 4] = [
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "cookie",
+];
+
+/// JSON fields redacted in the recorded bodies, matched case-insensitively on the key.
+const SENSITIVE_FIELDS: [&str; 5] = ["authorization", "token", "secret", "password", "authcode"];
+
+const REDACTED: &str = "***";
+
+lazy_static! {
+    static ref REQUESTS: Mutex<RequestLog> = Mutex::new(RequestLog::default());
+}
+
+#[derive(Default)]
+struct RequestLog {
+    next_id: u64,
+    requests: VecDeque<RecordedRequest>,
+}
+
+/// A management API request with the response it got, as shown in the inspector.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub id: String,
+    pub connector: String,
+    pub timestamp: DateTime<Local>,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    pub status: Option<u16>,
+    pub response: Option<String>,
+    pub error: Option<String>,
+    pub latency: Duration,
+}
+
+/// Adds a request to the log, dropping the oldest one when full.
+pub fn record(mut request: RecordedRequest) {
+    let mut log = REQUESTS.lock().unwrap_or_else(|err| err.into_inner());
+    log.next_id += 1;
+    request.id = log.next_id.to_string();
+    if log.requests.len() == CAPACITY {
+        log.requests.pop_front();
+    }
+    log.requests.push_back(request);
+}
+
+/// The recorded requests of a connector, newest first.
+pub fn requests(connector: &str) -> Vec<RecordedRequest> {
+    let log = REQUESTS.lock().unwrap_or_else(|err| err.into_inner());
+    log.requests
+        .iter()
+        .rev()
+        .filter(|request| request.connector == connector)
+        .cloned()
+        .collect()
+}
+
+/// Keeps the auth scheme (e.g. `Bearer`) but never the credentials.
+pub fn redact_header(name: &str, value: &str, extra: &[&str]) -> String {
+    let sensitive = SENSITIVE_HEADERS
+        .iter()
+        .chain(extra)
+        .any(|header| header.eq_ignore_ascii_case(name));
+
+    if !sensitive {
+        return value.to_string();
+    }
+    match value.split_once(' ') {
+        Some((scheme, _)) if matches!(scheme, "Basic" | "Bearer") => {
+            format!("{} {}", scheme, REDACTED)
+        }
+        _ => REDACTED.to_string(),
+    }
+}
+
+pub fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact_json(&mut json);
+            serde_json::to_string_pretty(&json).unwrap_or_default()
+        }
+        Err(_) => String::from_utf8_lossy(body).to_string(),
+    }
+}
+
+fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if value.is_string() && SENSITIVE_FIELDS.iter().any(|f| key.contains(f)) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+impl RecordedRequest {
+    /// A `curl` command replaying the request, reading each redacted header from its own
+    /// variable (e.g. `$EDC_X_API_KEY`) and basic auth from `$EDC_USER` and `$EDC_SECRET`.
+    pub fn to_curl(&self) -> String {
+        let mut parts = vec![format!("curl -X {} {}", self.method, quote(&self.url))];
+
+        for (name, value) in &self.headers {
+            let part = match value.strip_suffix(REDACTED) {
+                Some("Basic ") if name.eq_ignore_ascii_case("authorization") => {
+                    String::from("-u \"$EDC_USER:$EDC_SECRET\"")
+                }
+                Some(scheme) => format!("-H \"{}: {}${}\"", name, scheme, env_var(name)),
+                None => format!("-H {}", quote(&format!("{}: {}", name, value))),
+            };
+            parts.push(part);
+        }
+
+        if let Some(body) = &self.body {
+            parts.push(format!("--data-raw {}", quote(body)));
+        }
+
+        parts.join(" \\\n  ")
+    }
+}
+
+/// `EDC_` followed by the header name in upper snake case.
+fn env_var(header: &str) -> String {
+    let name = header
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() => c.to_ascii_uppercase(),
+            _ => '_',
+        })
+        .collect::<String>();
+    format!("EDC_{}", name)
+}
+
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_headers() {
+        let cases = [
+            ("Authorization", "Bearer abc", &[][..], "Bearer ***"),
+            ("authorization", "Basic dXNlcjpwdw==", &[], "Basic ***"),
+            ("X-Api-Key", "abc", &[], "***"),
+            ("Cookie", "session=abc", &[], "***"),
+            ("X-Gateway-Token", "abc", &["x-gateway-token"], "***"),
+            ("Content-Type", "application/json", &[], "application/json"),
+        ];
+        for (name, value, extra, expected) in cases {
+            assert_eq!(redact_header(name, value, extra), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn redacts_body_fields() {
+        let body = br#"{"clientSecret":"s","nested":[{"authCode":"c","id":"1"}],"tokenCount":3}"#;
+        let redacted: Value = serde_json::from_str(&redact_body(body)).unwrap();
+        assert_eq!(
+            redacted,
+            serde_json::json!({
+                "clientSecret": "***",
+                "nested": [{ "authCode": "***", "id": "1" }],
+                "tokenCount": 3,
+            })
+        );
+        assert_eq!(redact_body(b"token=abc"), "token=abc");
+    }
+
+    #[test]
+    fn curl_reads_redacted_headers_from_variables() {
+        let request = RecordedRequest {
+            id: String::new(),
+            connector: String::new(),
+            timestamp: Local::now(),
+            method: String::from("POST"),
+            url: String::from("http://localhost/management/v3/assets/request"),
+            headers: vec![
+                (String::from("authorization"), String::from("Basic ***")),
+                (String::from("x-api-key"), String::from("***")),
+                (
+                    String::from("proxy-authorization"),
+                    String::from("Bearer ***"),
+                ),
+                (
+                    String::from("content-type"),
+                    String::from("application/json"),
+                ),
+            ],
+            body: Some(String::from("{\"a\":\"it's\"}")),
+            status: None,
+            response: None,
+            error: None,
+            latency: Duration::ZERO,
+        };
+        assert_eq!(
+            request.to_curl(),
+            [
+                "curl -X POST 'http://localhost/management/v3/assets/request'",
+                "-u \"$EDC_USER:$EDC_SECRET\"",
+                "-H \"x-api-key: $EDC_X_API_KEY\"",
+                "-H \"proxy-authorization: Bearer $EDC_PROXY_AUTHORIZATION\"",
+                "-H 'content-type: application/json'",
+                r#"--data-raw '{"a":"it'\''s"}'"#,
+            ]
+            .join(" \\\n  ")
+        );
+    }
+}
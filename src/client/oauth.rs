@@ -0,0 +1,175 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use edc_connector_client::{EdcResult, Error};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+const DEFAULT_SCOPES: [&str; 2] = ["management-api:read", "management-api:write"];
+const ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// How the client authenticates against the token endpoint.
+#[derive(Clone)]
+pub enum ClientCredential {
+    Secret(String),
+    /// Signed JWT assertion (RFC 7523), with the PEM encoded private key.
+    PrivateKeyJwt {
+        key: String,
+        key_id: Option<String>,
+    },
+}
+
+#[derive(Clone)]
+pub struct OAuth2Settings {
+    pub client_id: String,
+    pub token_url: String,
+    pub credential: ClientCredential,
+    pub scopes: Option<Vec<String>>,
+    pub audience: Option<String>,
+}
+
+/// Client credentials flow with a cached access token.
+#[derive(Clone)]
+pub struct OAuth2(Arc<OAuth2Internal>);
+
+struct OAuth2Internal {
+    settings: OAuth2Settings,
+    signing_key: Option<(Algorithm, EncodingKey)>,
+    http: Client,
+    session: Mutex<Option<(String, Instant)>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Assertion<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    jti: String,
+    iat: u64,
+    exp: u64,
+}
+
+impl OAuth2 {
+    /// Fails when the private key of a `private_key_jwt` client cannot be parsed.
+    pub fn new(settings: OAuth2Settings, http: Client) -> EdcResult<OAuth2> {
+        let signing_key = match &settings.credential {
+            ClientCredential::Secret(_) => None,
+            ClientCredential::PrivateKeyJwt { key, .. } => Some(signing_key(key)?),
+        };
+        Ok(OAuth2(Arc::new(OAuth2Internal {
+            settings,
+            signing_key,
+            http,
+            session: Mutex::default(),
+        })))
+    }
+
+    pub async fn token(&self) -> EdcResult<String> {
+        let mut session = self.0.session.lock().await;
+        match session.as_ref() {
+            Some((token, expires_at)) if Instant::now() + Duration::from_secs(30) < *expires_at => {
+                Ok(token.clone())
+            }
+            _ => {
+                let (token, expires_at) = self.0.request_token().await?;
+                *session = Some((token.clone(), expires_at));
+                Ok(token)
+            }
+        }
+    }
+}
+
+impl OAuth2Internal {
+    async fn request_token(&self) -> EdcResult<(String, Instant)> {
+        let settings = &self.settings;
+        let scopes = settings
+            .scopes
+            .clone()
+            .unwrap_or_else(|| DEFAULT_SCOPES.map(String::from).to_vec())
+            .join(" ");
+
+        let mut form = vec![("grant_type", String::from("client_credentials"))];
+        if !scopes.is_empty() {
+            form.push(("scope", scopes));
+        }
+        if let Some(audience) = settings.audience.as_ref() {
+            form.push(("audience", audience.clone()));
+        }
+
+        let request = self.http.post(&settings.token_url);
+        let request = match &settings.credential {
+            ClientCredential::Secret(secret) => {
+                request.basic_auth(&settings.client_id, Some(secret))
+            }
+            ClientCredential::PrivateKeyJwt { key_id, .. } => {
+                form.push(("client_id", settings.client_id.clone()));
+                form.push(("client_assertion_type", ASSERTION_TYPE.to_string()));
+                form.push(("client_assertion", self.assertion(key_id.clone())?));
+                request
+            }
+        };
+
+        let response = request.form(&form).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(auth_error(format!(
+                "token endpoint returned {}: {}",
+                status, body
+            )));
+        }
+
+        let token = response.json::<TokenResponse>().await?;
+        let expires_at = Instant::now() + Duration::from_secs(token.expires_in.unwrap_or(3600));
+        Ok((token.access_token, expires_at))
+    }
+
+    fn assertion(&self, key_id: Option<String>) -> EdcResult<String> {
+        let (algorithm, key) = self
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| auth_error(String::from("missing private key")))?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let claims = Assertion {
+            iss: &self.settings.client_id,
+            sub: &self.settings.client_id,
+            aud: &self.settings.token_url,
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: now,
+            exp: now + 300,
+        };
+        let header = Header {
+            kid: key_id,
+            ..Header::new(*algorithm)
+        };
+
+        jsonwebtoken::encode(&header, &claims, key)
+            .map_err(|err| auth_error(format!("failed to sign client assertion: {}", err)))
+    }
+}
+
+/// RSA keys sign with RS256, EC keys with ES256.
+fn signing_key(pem: &str) -> EdcResult<(Algorithm, EncodingKey)> {
+    EncodingKey::from_rsa_pem(pem.as_bytes())
+        .map(|key| (Algorithm::RS256, key))
+        .or_else(|_| EncodingKey::from_ec_pem(pem.as_bytes()).map(|key| (Algorithm::ES256, key)))
+        .map_err(|err| auth_error(format!("invalid private key: {}", err)))
+}
+
+fn auth_error(msg: String) -> Error {
+    Error::Auth(msg.into())
+}
@@ -0,0 +1,42 @@
+use std::fs;
+
+use anyhow::Context;
+use reqwest::{Certificate, Client, Identity, Proxy};
+
+use crate::config::TransportConfig;
+
+/// Builds the HTTP client used for both the management API and the token endpoint.
+pub fn http_client(transport: &TransportConfig) -> anyhow::Result<Client> {
+    let mut builder = Client::builder();
+
+    if let Some(proxy) = &transport.proxy {
+        builder = builder.proxy(Proxy::all(proxy)?);
+    }
+
+    for path in &transport.ca_certs {
+        let pem = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+        for cert in Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("parsing {}", path.display()))?
+        {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    if let (Some(cert), Some(key)) = (&transport.client_cert, &transport.client_key) {
+        let cert_pem = fs::read(cert).with_context(|| format!("reading {}", cert.display()))?;
+        let key_pem = fs::read(key).with_context(|| format!("reading {}", key.display()))?;
+        builder = builder.identity(
+            Identity::from_pkcs8_pem(&cert_pem, &key_pem).context("loading client certificate")?,
+        );
+    }
+
+    if transport.insecure_skip_verify {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    if let Some(timeout) = transport.timeout() {
+        builder = builder.timeout(timeout);
+    }
+
+    Ok(builder.build()?)
+}
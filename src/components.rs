@@ -1,26 +1,54 @@
 use std::{fmt::Debug, sync::Arc};
 
+use chrono::{DateTime, Local};
 use crossterm::event::{Event, KeyEvent};
+use edc_connector_client::types::query::Query;
 use futures::{future::BoxFuture, FutureExt};
-use ratatui::{layout::Rect, Frame};
-
-use crate::types::nav::Nav;
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    Frame,
+};
+
+use crate::types::{
+    api_error::ApiError,
+    connector::{Connector, Credentials},
+    copy::CopyRequest,
+    nav::Nav,
+};
 
 pub mod agreements;
+pub mod api_error;
+pub mod apply;
 pub mod assets;
+pub mod compare;
+pub mod config_error;
 pub mod connectors;
 pub mod contract_definitions;
 pub mod contract_negotiations;
+pub mod copy;
+pub mod credentials;
+pub mod dashboard;
 pub mod dataplanes;
 pub mod edrs;
 pub mod footer;
 pub mod header;
 pub mod launch_bar;
+pub mod notifications;
+pub mod participants;
 pub mod policies;
+pub mod requests;
 pub mod resources;
 pub mod table;
 pub mod transfer_processes;
 
+pub fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+    area
+}
+
 pub trait StatelessComponent {
     type Props: Send;
 
@@ -117,8 +145,15 @@ pub enum Action {
     Quit,
     Esc,
     NavTo(Nav),
+    DrillDown(Nav, Query),
+    SetParticipantContext(Option<String>),
+    RequestCredentials(Connector),
+    SetCredentials(Connector, Credentials),
+    Copy(CopyRequest),
     ChangeSheet,
     Notification(Notification),
+    /// A failed request, summarized in the footer with its violations in a popup.
+    Error(ApiError),
 }
 
 #[derive(Debug, Clone)]
@@ -126,6 +161,8 @@ pub struct Notification {
     msg: String,
     kind: NotificationKind,
     timeout: u64,
+    timestamp: DateTime<Local>,
+    origin: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -140,15 +177,18 @@ impl Notification {
             msg,
             kind: NotificationKind::Error,
             timeout: 5,
+            timestamp: Local::now(),
+            origin: None,
         }
     }
 
-    #[allow(dead_code)]
     pub fn info(msg: String) -> Notification {
         Notification {
             msg,
             kind: NotificationKind::Info,
             timeout: 5,
+            timestamp: Local::now(),
+            origin: None,
         }
     }
 
@@ -163,9 +203,23 @@ impl Notification {
     pub fn timeout(&self) -> u64 {
         self.timeout
     }
+
+    pub fn timestamp(&self) -> &DateTime<Local> {
+        &self.timestamp
+    }
+
+    /// The view that was active when the notification was raised.
+    pub fn origin(&self) -> Option<&str> {
+        self.origin.as_deref()
+    }
+
+    pub fn with_origin(mut self, origin: String) -> Self {
+        self.origin = Some(origin);
+        self
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum NotificationKind {
     Error,
     Info,
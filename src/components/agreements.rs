@@ -1,5 +1,4 @@
 use edc_connector_client::types::contract_agreement::ContractAgreement;
-use ratatui::widgets::Row;
 
 use crate::components::resources::FieldValue;
 
@@ -22,27 +21,27 @@ pub type ContractAgreementsComponent =
     ResourcesComponent<ContractAgreementEntry, ContractAgreementEntry>;
 
 impl TableEntry for ContractAgreementEntry {
-    fn row(&self) -> Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let policy = serde_json::to_string(self.0.policy()).unwrap();
-        Row::new(vec![
+        vec![
             self.0.id().to_string(),
             format!("{:?}", self.0.contract_signing_date()),
             format!("{:?}", self.0.asset_id()),
             self.0.consumer_id().to_string(),
             self.0.provider_id().to_string(),
             policy,
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "ID",
             "SIGN_DATE",
             "ASSET_ID",
             "CONSUMER_ID",
             "PROVIDER_ID",
             "POLICY",
-        ])
+        ]
     }
 }
 
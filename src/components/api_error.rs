@@ -0,0 +1,62 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::types::api_error::ApiError;
+
+use super::{popup_area, StatelessComponent};
+
+#[derive(Default)]
+pub struct ApiErrorComponent {}
+
+impl StatelessComponent for ApiErrorComponent {
+    type Props = ApiError;
+
+    fn view(&mut self, props: &Self::Props, f: &mut Frame, rect: Rect) {
+        let label =
+            |label: &str| Span::styled(format!("{}: ", label), Style::default().fg(Color::Yellow));
+
+        let mut lines = vec![Line::from(props.message().to_string()), Line::default()];
+        for (idx, violation) in props.violations().iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}. ", idx + 1), Style::default().fg(Color::Red)),
+                Span::raw(violation.message.clone()),
+            ]));
+            if let Some(kind) = violation.kind.as_ref() {
+                lines.push(Line::from(vec![label("   type"), Span::raw(kind.clone())]));
+            }
+            if let Some(path) = violation.path.as_ref() {
+                lines.push(Line::from(vec![label("   path"), Span::raw(path.clone())]));
+            }
+            if let Some(value) = violation.invalid_value.as_ref() {
+                lines.push(Line::from(vec![
+                    label("   invalid value"),
+                    Span::raw(value.to_string()),
+                ]));
+            }
+        }
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            "<esc> to dismiss",
+            Style::default().fg(Color::Gray),
+        ));
+
+        let block = Block::default()
+            .title("Request failed")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+
+        let area = popup_area(rect, 60, 50);
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use std::{collections::HashMap, future::Future, path::PathBuf, sync::Arc};
+
+use crossterm::event::{Event, KeyCode};
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::apply::StepOutcome,
+    types::{
+        api_error::ApiError,
+        connector::Connector,
+        info::InfoSheet,
+        manifest::{manifest_path, Plan, PlanAction, PlanStep},
+    },
+    widgets::form::{msg::FormMsg, text::TextField, FieldComponent, Form},
+};
+
+use self::msg::ApplyMsg;
+
+use super::{
+    popup_area,
+    table::{msg::TableMsg, TableEntry, UiTable},
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
+};
+
+pub mod msg;
+
+pub type ApplyTable = UiTable<StepEntry, Box<ApplyMsg>>;
+
+pub type OnPlan = Arc<
+    dyn Fn(&Connector, PathBuf, bool) -> BoxFuture<'static, anyhow::Result<Plan>> + Send + Sync,
+>;
+
+pub type OnApply =
+    Arc<dyn Fn(&Connector, Plan) -> BoxFuture<'static, Vec<StepOutcome>> + Send + Sync>;
+
+/// A plan step, with the outcome once the plan has been applied.
+#[derive(Debug)]
+pub struct StepEntry {
+    step: PlanStep,
+    outcome: Option<Result<(), String>>,
+}
+
+impl StepEntry {
+    fn status(&self) -> (&'static str, Style) {
+        match self.outcome.as_ref() {
+            None => ("pending", Style::default()),
+            Some(Ok(())) => ("done", Style::default().fg(Color::Green)),
+            Some(Err(_)) => ("failed", Style::default().fg(Color::Red)),
+        }
+    }
+}
+
+impl TableEntry for StepEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.step.action.label().to_string(),
+            self.step.kind.label().to_string(),
+            self.step.id.clone(),
+            self.step.changes.len().to_string(),
+            self.status().0.to_string(),
+        ]
+    }
+
+    fn row(&self) -> Row<'_> {
+        let (status, style) = self.status();
+        Row::new(vec![
+            Cell::from(self.step.action.label()).style(action_style(self.step.action)),
+            Cell::from(self.step.kind.label()),
+            Cell::from(self.step.id.as_str()),
+            Cell::from(self.step.changes.len().to_string()),
+            Cell::from(status).style(style),
+        ])
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["ACTION", "KIND", "ID", "CHANGES", "STATUS"]
+    }
+}
+
+/// Plans and applies a manifest of assets, policies and contract definitions on the selected
+/// connector.
+pub struct ApplyComponent {
+    table: ApplyTable,
+    connector: Option<Connector>,
+    path: Option<String>,
+    prune: bool,
+    plan: Option<Plan>,
+    applied: bool,
+    form: Option<Form<String>>,
+    confirming: bool,
+    on_plan: Option<OnPlan>,
+    on_apply: Option<OnApply>,
+}
+
+impl Default for ApplyComponent {
+    fn default() -> Self {
+        Self {
+            table: ApplyTable::new("Plan".to_string()),
+            connector: None,
+            path: None,
+            prune: false,
+            plan: None,
+            applied: false,
+            form: None,
+            confirming: false,
+            on_plan: None,
+            on_apply: None,
+        }
+    }
+}
+
+impl ApplyComponent {
+    pub fn on_plan<F, Fut>(mut self, on_plan: F) -> Self
+    where
+        F: Fn(Connector, PathBuf, bool) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<Plan>> + Send,
+    {
+        let handler = Arc::new(on_plan);
+        self.on_plan = Some(Arc::new(move |conn, path, prune| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, path, prune).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn on_apply<F, Fut>(mut self, on_apply: F) -> Self
+    where
+        F: Fn(Connector, Plan) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<StepOutcome>> + Send,
+    {
+        let handler = Arc::new(on_apply);
+        self.on_apply = Some(Arc::new(move |conn, plan| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, plan).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn info_sheet(&self) -> InfoSheet {
+        self.table
+            .info_sheet()
+            .key_binding("<o>", "Open manifest")
+            .key_binding("<r>", "Refresh plan")
+            .key_binding("<p>", "Toggle prune")
+            .key_binding("<a>", "Apply plan")
+    }
+
+    fn form(&self) -> Form<String> {
+        Form::default()
+            .field(
+                TextField::builder()
+                    .name("path".to_string())
+                    .label("Manifest file or directory".to_string())
+                    .initial_value(self.path.clone().unwrap_or_default())
+                    .selected(true)
+                    .build()
+                    .unwrap(),
+            )
+            .on_confirm(Self::parse_fields)
+    }
+
+    fn parse_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<String> {
+        let path: String = fields["path"].clone().try_into()?;
+        let path = path.trim().to_string();
+        if path.is_empty() {
+            anyhow::bail!("Path is required");
+        }
+        Ok(path)
+    }
+
+    fn form_msg(msg: FormMsg<String>) -> ApplyMsg {
+        match msg {
+            FormMsg::Local(local) => ApplyMsg::Form(FormMsg::Local(local)),
+            FormMsg::Outer(path) => ApplyMsg::Open(path),
+        }
+    }
+
+    fn plan(&mut self) -> anyhow::Result<ComponentReturn<ApplyMsg>> {
+        match (
+            self.connector.clone(),
+            self.on_plan.clone(),
+            self.path.as_deref().map(manifest_path),
+        ) {
+            (Some(connector), Some(on_plan), Some(path)) => {
+                let prune = self.prune;
+                Ok(ComponentReturn::cmd(
+                    async move {
+                        match on_plan(&connector, path, prune).await {
+                            Ok(plan) => Ok(vec![ApplyMsg::Planned(plan).into()]),
+                            Err(err) => Ok(vec![ApplyMsg::Failed(ApiError::new(&err)).into()]),
+                        }
+                    }
+                    .boxed(),
+                ))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn apply(&mut self) -> anyhow::Result<ComponentReturn<ApplyMsg>> {
+        self.confirming = false;
+        match (
+            self.connector.clone(),
+            self.on_apply.clone(),
+            self.plan.clone(),
+        ) {
+            (Some(connector), Some(on_apply), Some(plan)) if !self.applied => {
+                Ok(ComponentReturn::cmd(
+                    async move {
+                        Ok(vec![
+                            ApplyMsg::Applied(on_apply(&connector, plan).await).into()
+                        ])
+                    }
+                    .boxed(),
+                ))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn planned(&mut self, plan: Plan) -> anyhow::Result<ComponentReturn<ApplyMsg>> {
+        let entries = plan
+            .steps()
+            .iter()
+            .cloned()
+            .map(|step| StepEntry {
+                step,
+                outcome: None,
+            })
+            .collect();
+        self.table.update_elements(entries);
+        self.table.select(0);
+        self.plan = Some(plan);
+        self.applied = false;
+        Ok(ComponentReturn::empty())
+    }
+
+    fn applied(&mut self, outcomes: Vec<StepOutcome>) -> anyhow::Result<ComponentReturn<ApplyMsg>> {
+        let failed = outcomes.iter().filter(|o| o.error.is_some()).count();
+        for (entry, outcome) in self.table.elements_mut().iter_mut().zip(outcomes) {
+            entry.outcome = Some(outcome.error.map_or(Ok(()), Err));
+        }
+        self.applied = true;
+
+        let noty = if failed == 0 {
+            Notification::info("Plan applied".to_string())
+        } else {
+            Notification::error(format!("{} step(s) failed", failed))
+        };
+        Ok(ComponentReturn::action(Action::Notification(noty)))
+    }
+
+    fn detail(&self) -> Vec<Line<'_>> {
+        match self.table.selected() {
+            Some(entry) => {
+                let mut lines = vec![];
+                if let Some(Err(error)) = entry.outcome.as_ref() {
+                    lines.push(Line::styled(
+                        error.as_str(),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+                lines.extend(entry.step.changes.iter().map(|c| Line::from(c.as_str())));
+                lines
+            }
+            None if self.path.is_none() => vec![Line::from("<o> to open a manifest")],
+            None if self.plan.is_some() => vec![Line::from("Nothing to apply, up to date")],
+            None => vec![],
+        }
+    }
+
+    fn popup_block(title: &str) -> Block<'_> {
+        Block::default()
+            .title_top(
+                Line::from(Span::styled(
+                    format!(" {} ", title),
+                    Style::default().fg(Color::Red),
+                ))
+                .centered(),
+            )
+            .borders(Borders::ALL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for ApplyComponent {
+    type Msg = ApplyMsg;
+    type Props = Connector;
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        self.connector = Some(props);
+        self.plan = None;
+        self.applied = false;
+        self.confirming = false;
+        self.table.update_elements(vec![]);
+        if self.path.is_some() {
+            self.form = None;
+            self.plan()
+        } else {
+            self.form = Some(self.form());
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let summary = match (self.plan.as_ref(), self.applied) {
+            (Some(_), true) => "applied".to_string(),
+            (Some(plan), false) => plan.summary(),
+            (None, _) => "no plan".to_string(),
+        };
+        let title = format!(
+            " Apply {} (prune: {}, {}) ",
+            self.path.as_deref().unwrap_or("-"),
+            if self.prune { "on" } else { "off" },
+            summary
+        );
+        let block = Block::default()
+            .title_top(Line::from(Span::styled(title, Style::default().fg(Color::Blue))).centered())
+            .borders(Borders::ALL);
+        let area = block.inner(rect);
+        f.render_widget(block, rect);
+
+        let [table_area, detail_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(8)]).areas(area);
+
+        self.table.view(f, table_area);
+
+        let p = Paragraph::new(self.detail())
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP));
+        f.render_widget(p, detail_area);
+
+        if let Some(form) = self.form.as_mut() {
+            let block = Self::popup_block("Open manifest");
+            let area = popup_area(f.area(), 50, 20);
+            let content = block.inner(area);
+            f.render_widget(Clear, area);
+            f.render_widget(block, area);
+            form.view(f, content);
+        }
+
+        if let (true, Some(plan)) = (self.confirming, self.plan.as_ref()) {
+            let area = popup_area(f.area(), 40, 15);
+            let text = Paragraph::new(vec![
+                Line::from(format!("Apply the plan? {}", plan.summary())),
+                Line::from(""),
+                Line::from("<y> confirm, <n/esc> cancel"),
+            ])
+            .centered()
+            .block(Self::popup_block("Apply"));
+            f.render_widget(Clear, area);
+            f.render_widget(text, area);
+        }
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            ApplyMsg::TableEvent(table) => {
+                Self::forward_update(&mut self.table, table.into(), ApplyMsg::TableEvent).await
+            }
+            ApplyMsg::ShowForm => {
+                self.form = Some(self.form());
+                Ok(ComponentReturn::empty())
+            }
+            ApplyMsg::HideForm => {
+                self.form = None;
+                Ok(ComponentReturn::empty())
+            }
+            ApplyMsg::Form(msg) => match self.form.as_mut() {
+                Some(form) => Self::forward_update(form, msg.into(), Self::form_msg).await,
+                None => Ok(ComponentReturn::empty()),
+            },
+            ApplyMsg::Open(path) => {
+                self.form = None;
+                self.path = Some(path);
+                self.plan()
+            }
+            ApplyMsg::Refresh => self.plan(),
+            ApplyMsg::TogglePrune => {
+                self.prune = !self.prune;
+                self.plan()
+            }
+            ApplyMsg::Planned(plan) => self.planned(plan),
+            ApplyMsg::AskApply => {
+                self.confirming = self
+                    .plan
+                    .as_ref()
+                    .is_some_and(|plan| !plan.is_empty() && !self.applied);
+                Ok(ComponentReturn::empty())
+            }
+            ApplyMsg::CancelApply => {
+                self.confirming = false;
+                Ok(ComponentReturn::empty())
+            }
+            ApplyMsg::ConfirmApply => self.apply(),
+            ApplyMsg::Applied(outcomes) => self.applied(outcomes),
+            ApplyMsg::Failed(error) => Ok(ComponentReturn::action(Action::Error(error))),
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if let Some(form) = self.form.as_mut() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ApplyMsg::HideForm.into()])
+                }
+                evt => Self::forward_event(form, evt, Self::form_msg),
+            };
+        }
+
+        if self.confirming {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) => match key.code {
+                    KeyCode::Char('y') => Ok(vec![ApplyMsg::ConfirmApply.into()]),
+                    KeyCode::Char('n') | KeyCode::Esc => Ok(vec![ApplyMsg::CancelApply.into()]),
+                    _ => Ok(vec![]),
+                },
+                _ => Ok(vec![]),
+            };
+        }
+
+        if let ComponentEvent::Event(Event::Key(key)) = &evt {
+            match key.code {
+                KeyCode::Char('o') => return Ok(vec![ApplyMsg::ShowForm.into()]),
+                KeyCode::Char('r') => return Ok(vec![ApplyMsg::Refresh.into()]),
+                KeyCode::Char('p') => return Ok(vec![ApplyMsg::TogglePrune.into()]),
+                KeyCode::Char('a') => return Ok(vec![ApplyMsg::AskApply.into()]),
+                _ => {}
+            }
+        }
+
+        Self::forward_event(&mut self.table, evt, |msg| match msg {
+            TableMsg::Local(table) => ApplyMsg::TableEvent(TableMsg::Local(table)),
+            TableMsg::Outer(outer) => *outer,
+        })
+    }
+}
+
+fn action_style(action: PlanAction) -> Style {
+    match action {
+        PlanAction::Create => Style::default().fg(Color::Green),
+        PlanAction::Update => Style::default().fg(Color::Yellow),
+        PlanAction::Delete => Style::default().fg(Color::Red),
+    }
+}
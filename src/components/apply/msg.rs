@@ -0,0 +1,23 @@
+use crate::{
+    app::apply::StepOutcome,
+    components::table::msg::TableMsg,
+    types::{api_error::ApiError, manifest::Plan},
+    widgets::form::msg::FormMsg,
+};
+
+#[derive(Debug)]
+pub enum ApplyMsg {
+    TableEvent(TableMsg<Box<ApplyMsg>>),
+    ShowForm,
+    HideForm,
+    Form(FormMsg<String>),
+    Open(String),
+    Refresh,
+    TogglePrune,
+    Planned(Plan),
+    AskApply,
+    CancelApply,
+    ConfirmApply,
+    Applied(Vec<StepOutcome>),
+    Failed(ApiError),
+}
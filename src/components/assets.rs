@@ -5,7 +5,6 @@ use super::{
     table::TableEntry,
 };
 use edc_connector_client::types::asset::Asset;
-use ratatui::widgets::Row;
 
 pub type AssetsMsg = ResourcesMsg<AssetEntry, AssetEntry>;
 pub type AssetsComponent = ResourcesComponent<AssetEntry, AssetEntry>;
@@ -20,25 +19,20 @@ impl AssetEntry {
 }
 
 impl TableEntry for AssetEntry {
-    fn row(&self) -> Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let properties = serde_json::to_string(self.0.properties()).unwrap();
         let private_properties = serde_json::to_string(self.0.private_properties()).unwrap();
         let data_address = serde_json::to_string(self.0.data_address()).unwrap();
-        Row::new(vec![
+        vec![
             self.0.id().to_string(),
             properties,
             private_properties,
             data_address,
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
-            "ID",
-            "PROPERTIES",
-            "PRIVATE PROPERTIES",
-            "DATA ADDRESS",
-        ])
+    fn columns() -> Vec<&'static str> {
+        vec!["ID", "PROPERTIES", "PRIVATE PROPERTIES", "DATA ADDRESS"]
     }
 }
 
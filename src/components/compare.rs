@@ -0,0 +1,373 @@
+use std::{future::Future, sync::Arc};
+
+use crossterm::event::{Event, KeyCode};
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Wrap},
+    Frame,
+};
+use serde_json::Value;
+
+use crate::types::{
+    api_error::ApiError,
+    compare::{CompareEntry, CompareStatus, Comparison},
+    connector::Connector,
+    info::InfoSheet,
+    manifest::ManifestKind,
+};
+
+use self::msg::CompareMsg;
+
+use super::{
+    table::{msg::TableMsg, TableEntry, UiTable},
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn,
+};
+
+pub mod msg;
+
+pub type CompareTable = UiTable<CompareRow, Box<CompareMsg>>;
+
+pub type OnCompare = Arc<
+    dyn Fn(&Connector, Connector, ManifestKind) -> BoxFuture<'static, anyhow::Result<Comparison>>
+        + Send
+        + Sync,
+>;
+
+#[derive(Debug)]
+pub struct CompareRow(CompareEntry);
+
+impl CompareRow {
+    fn changes(&self) -> String {
+        match self.0.status {
+            CompareStatus::Changed => self.0.changes.len().to_string(),
+            _ => String::from("-"),
+        }
+    }
+}
+
+impl TableEntry for CompareRow {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.status.label().to_string(),
+            self.0.id.clone(),
+            self.changes(),
+        ]
+    }
+
+    fn row(&self) -> Row<'_> {
+        Row::new(vec![
+            Cell::from(self.0.status.label()).style(status_style(self.0.status)),
+            Cell::from(self.0.id.as_str()),
+            Cell::from(self.changes()),
+        ])
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["STATUS", "ID", "CHANGES"]
+    }
+}
+
+/// Assets, policies or contract definitions of the selected connector compared with another
+/// configured connector.
+pub struct CompareComponent {
+    table: CompareTable,
+    left: Option<Connector>,
+    targets: Vec<Connector>,
+    target: usize,
+    kind: ManifestKind,
+    unchanged: Option<usize>,
+    on_compare: Option<OnCompare>,
+}
+
+impl Default for CompareComponent {
+    fn default() -> Self {
+        Self {
+            table: CompareTable::new("Compare".to_string()),
+            left: None,
+            targets: vec![],
+            target: 0,
+            kind: ManifestKind::Asset,
+            unchanged: None,
+            on_compare: None,
+        }
+    }
+}
+
+impl CompareComponent {
+    pub fn on_compare<F, Fut>(mut self, on_compare: F) -> Self
+    where
+        F: Fn(Connector, Connector, ManifestKind) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<Comparison>> + Send,
+    {
+        let handler = Arc::new(on_compare);
+        self.on_compare = Some(Arc::new(move |conn, right, kind| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, right, kind).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn info_sheet(&self) -> InfoSheet {
+        self.table
+            .info_sheet()
+            .key_binding("<o>", "Other connector")
+            .key_binding("<t>", "Resource type")
+            .key_binding("<r>", "Refresh")
+    }
+
+    fn right(&self) -> Option<&Connector> {
+        self.targets.get(self.target)
+    }
+
+    fn compare(&mut self) -> anyhow::Result<ComponentReturn<CompareMsg>> {
+        self.unchanged = None;
+        self.table.update_elements(vec![]);
+        match (
+            self.left.clone(),
+            self.right().cloned(),
+            self.on_compare.clone(),
+        ) {
+            (Some(left), Some(right), Some(on_compare)) => {
+                let kind = self.kind;
+                Ok(ComponentReturn::cmd(
+                    async move {
+                        let name = right.config().name().to_string();
+                        match on_compare(&left, right, kind).await {
+                            Ok(comparison) => {
+                                Ok(vec![CompareMsg::Compared(kind, name, comparison).into()])
+                            }
+                            Err(err) => Ok(vec![CompareMsg::Failed(ApiError::new(&err)).into()]),
+                        }
+                    }
+                    .boxed(),
+                ))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn compared(
+        &mut self,
+        kind: ManifestKind,
+        name: String,
+        comparison: Comparison,
+    ) -> anyhow::Result<ComponentReturn<CompareMsg>> {
+        // Results of a previous target or kind are dropped.
+        if kind != self.kind || self.right().map(|c| c.config().name()) != Some(name.as_str()) {
+            return Ok(ComponentReturn::empty());
+        }
+        self.unchanged = Some(comparison.unchanged);
+        self.table
+            .update_elements(comparison.entries.into_iter().map(CompareRow).collect());
+        self.table.select(0);
+        Ok(ComponentReturn::empty())
+    }
+
+    fn title(&self) -> String {
+        let left = self.left.as_ref().map(|c| c.config().name()).unwrap_or("-");
+        let right = self.right().map(|c| c.config().name()).unwrap_or("-");
+        let summary = match self.unchanged {
+            Some(unchanged) => format!(
+                "{} differ, {} identical",
+                self.table.elements().len(),
+                unchanged
+            ),
+            None => String::from("loading..."),
+        };
+        format!(
+            " Compare {}: {} with {} ({}) ",
+            kind_title(self.kind),
+            left,
+            right,
+            summary
+        )
+    }
+
+    fn detail(&self) -> Vec<Line<'_>> {
+        let (Some(left), Some(right)) = (self.left.as_ref(), self.right()) else {
+            return vec![Line::from("No other connector to compare with")];
+        };
+        let Some(CompareRow(entry)) = self.table.selected() else {
+            return vec![];
+        };
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                format!("- {}", left.config().name()),
+                Style::default().fg(Color::Red),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("+ {}", right.config().name()),
+                Style::default().fg(Color::Green),
+            ),
+        ])];
+
+        match (entry.left.as_ref(), entry.right.as_ref()) {
+            (Some(_), Some(_)) => {
+                for change in entry.changes.iter() {
+                    lines.push(Line::styled(
+                        change.path.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ));
+                    lines.push(Line::styled(
+                        format!("- {}", display(&change.left)),
+                        Style::default().fg(Color::Red),
+                    ));
+                    lines.push(Line::styled(
+                        format!("+ {}", display(&change.right)),
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+            }
+            (Some(fields), None) => lines.extend(field_lines(fields, "-", Color::Red)),
+            (None, Some(fields)) => lines.extend(field_lines(fields, "+", Color::Green)),
+            (None, None) => {}
+        }
+        lines
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for CompareComponent {
+    type Msg = CompareMsg;
+    type Props = (Connector, Vec<Connector>);
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        let (left, connectors) = props;
+        let previous = self.right().map(|c| c.config().name().to_string());
+        self.targets = connectors
+            .into_iter()
+            .filter(|c| c.config().name() != left.config().name())
+            .collect();
+        self.target = previous
+            .and_then(|name| self.targets.iter().position(|c| c.config().name() == name))
+            .unwrap_or(0);
+        self.left = Some(left);
+        self.compare()
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let block = Block::default()
+            .title_top(
+                Line::from(Span::styled(self.title(), Style::default().fg(Color::Blue))).centered(),
+            )
+            .borders(Borders::ALL);
+        let area = block.inner(rect);
+        f.render_widget(block, rect);
+
+        let [table_area, detail_area] =
+            Layout::vertical([Constraint::Percentage(40), Constraint::Fill(1)]).areas(area);
+
+        self.table.view(f, table_area);
+
+        let p = Paragraph::new(self.detail())
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP));
+        f.render_widget(p, detail_area);
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            CompareMsg::TableEvent(table) => {
+                Self::forward_update(&mut self.table, table.into(), CompareMsg::TableEvent).await
+            }
+            CompareMsg::NextTarget => {
+                if self.targets.len() > 1 {
+                    self.target = (self.target + 1) % self.targets.len();
+                    self.compare()
+                } else {
+                    Ok(ComponentReturn::empty())
+                }
+            }
+            CompareMsg::NextKind => {
+                self.kind = match self.kind {
+                    ManifestKind::Asset => ManifestKind::Policy,
+                    ManifestKind::Policy => ManifestKind::ContractDefinition,
+                    ManifestKind::ContractDefinition => ManifestKind::Asset,
+                };
+                self.compare()
+            }
+            CompareMsg::Refresh => self.compare(),
+            CompareMsg::Compared(kind, name, comparison) => self.compared(kind, name, comparison),
+            CompareMsg::Failed(error) => Ok(ComponentReturn::action(Action::Error(error))),
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if let ComponentEvent::Event(Event::Key(key)) = &evt {
+            match key.code {
+                KeyCode::Char('o') => return Ok(vec![CompareMsg::NextTarget.into()]),
+                KeyCode::Char('t') => return Ok(vec![CompareMsg::NextKind.into()]),
+                KeyCode::Char('r') => return Ok(vec![CompareMsg::Refresh.into()]),
+                _ => {}
+            }
+        }
+
+        Self::forward_event(&mut self.table, evt, |msg| match msg {
+            TableMsg::Local(table) => CompareMsg::TableEvent(TableMsg::Local(table)),
+            TableMsg::Outer(outer) => *outer,
+        })
+    }
+}
+
+/// The fields of a resource present on one side only, as built by `DrawableResource::to_json`.
+fn field_lines(fields: &Value, sign: &str, color: Color) -> Vec<Line<'static>> {
+    let Some(fields) = fields.as_object() else {
+        return vec![];
+    };
+    fields
+        .iter()
+        .flat_map(|(name, value)| {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                value => serde_json::to_string_pretty(value).unwrap_or_default(),
+            };
+            let mut lines = value.lines();
+            let first = Line::styled(
+                format!("{} {}: {}", sign, name, lines.next().unwrap_or_default()),
+                Style::default().fg(color),
+            );
+            std::iter::once(first)
+                .chain(lines.map(|line| {
+                    Line::styled(format!("{}   {}", sign, line), Style::default().fg(color))
+                }))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::from("<missing>"),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn kind_title(kind: ManifestKind) -> &'static str {
+    match kind {
+        ManifestKind::Policy => "policies",
+        ManifestKind::Asset => "assets",
+        ManifestKind::ContractDefinition => "contract definitions",
+    }
+}
+
+fn status_style(status: CompareStatus) -> Style {
+    match status {
+        CompareStatus::Added => Style::default().fg(Color::Green),
+        CompareStatus::Removed => Style::default().fg(Color::Red),
+        CompareStatus::Changed => Style::default().fg(Color::Yellow),
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{
+    components::table::msg::TableMsg,
+    types::{api_error::ApiError, compare::Comparison, manifest::ManifestKind},
+};
+
+#[derive(Debug)]
+pub enum CompareMsg {
+    TableEvent(TableMsg<Box<CompareMsg>>),
+    NextTarget,
+    NextKind,
+    Refresh,
+    /// The comparison of a kind of resources with the named connector.
+    Compared(ManifestKind, String, Comparison),
+    Failed(ApiError),
+}
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::config::ConfigError;
+
+use super::{popup_area, StatelessComponent};
+
+pub struct ConfigErrorProps {
+    pub path: Option<PathBuf>,
+    pub error: ConfigError,
+}
+
+#[derive(Default)]
+pub struct ConfigErrorComponent {}
+
+impl StatelessComponent for ConfigErrorComponent {
+    type Props = ConfigErrorProps;
+
+    fn view(&mut self, props: &Self::Props, f: &mut Frame, rect: Rect) {
+        let mut lines = vec![];
+        if let Some(path) = props.path.as_ref() {
+            lines.push(Line::from(vec![
+                Span::styled("File: ", Style::default().fg(Color::Yellow)),
+                Span::raw(path.display().to_string()),
+            ]));
+            lines.push(Line::default());
+        }
+        lines.extend(
+            props
+                .error
+                .messages()
+                .into_iter()
+                .map(|msg| Line::from(format!("- {}", msg))),
+        );
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            "Fix the file to reload it automatically, <esc> to dismiss",
+            Style::default().fg(Color::Gray),
+        ));
+
+        let block = Block::default()
+            .title("Configuration error")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+
+        let area = popup_area(rect, 60, 40);
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+}
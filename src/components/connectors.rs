@@ -1,43 +1,93 @@
-use ratatui::{layout::Rect, widgets::Row, Frame};
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
 
-use crate::types::{connector::Connector, info::InfoSheet, nav::Nav};
+use crossterm::event::{Event, KeyCode};
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row},
+    Frame,
+};
+
+use crate::{
+    config::{AuthKind, ConnectorApiVersion, ConnectorConfig},
+    types::{
+        connector::{Connector, ConnectorStatus, NewConnector},
+        info::InfoSheet,
+        nav::Nav,
+    },
+    widgets::form::{msg::FormMsg, text::TextField, FieldComponent, Form},
+};
 
 use self::msg::ConnectorsMsg;
 
 use super::{
+    popup_area,
     table::{msg::TableMsg, TableEntry, UiTable},
-    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn,
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
 };
 
 pub mod msg;
 
 pub type ConnectorsTable = UiTable<ConnectorEntry, Box<ConnectorsMsg>>;
 
-#[derive(Debug, Default)]
+pub type OnHealthCheck = Arc<dyn Fn(&Connector) -> BoxFuture<'static, Connector> + Send + Sync>;
+
+pub type OnSave = Arc<
+    dyn Fn(Option<String>, NewConnector) -> BoxFuture<'static, anyhow::Result<Vec<Connector>>>
+        + Send
+        + Sync,
+>;
+
+pub type OnRemove =
+    Arc<dyn Fn(&Connector) -> BoxFuture<'static, anyhow::Result<Vec<Connector>>> + Send + Sync>;
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
 pub struct ConnectorsComponent {
     table: ConnectorsTable,
     selected: Option<Connector>,
+    on_health_check: Option<OnHealthCheck>,
+    generation: u64,
+    form: Option<ConnectorForm>,
+    removing: Option<Connector>,
+    on_save: Option<OnSave>,
+    on_remove: Option<OnRemove>,
+}
+
+struct ConnectorForm {
+    editing: Option<String>,
+    form: Form<NewConnector>,
 }
 
 #[derive(Debug)]
 pub struct ConnectorEntry(Connector);
 
 impl TableEntry for ConnectorEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.config().name().to_string(),
+            self.0.config().address().to_string(),
+            version_label(&self.0),
+            self.0.config().auth().kind().to_string(),
+            self.0.status().label().to_string(),
+        ]
+    }
+
     fn row(&self) -> Row<'_> {
         Row::new(vec![
-            self.0.config().name(),
-            self.0.config().address(),
-            match self.0.config().version() {
-                crate::config::ConnectorApiVersion::V3 => "v3",
-                crate::config::ConnectorApiVersion::V4 => "v4",
-            },
-            self.0.config().auth().kind(),
-            self.0.status().as_str(),
+            Cell::from(self.0.config().name()),
+            Cell::from(self.0.config().address()),
+            Cell::from(version_label(&self.0)),
+            Cell::from(self.0.config().auth().kind()),
+            Cell::from(self.0.status().label()).style(status_style(self.0.status())),
         ])
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec!["NAME", "ADDRESS", "API VERSION", "AUTH", "STATUS"])
+    fn columns() -> Vec<&'static str> {
+        vec!["NAME", "ADDRESS", "API VERSION", "AUTH", "STATUS"]
     }
 }
 
@@ -46,8 +96,38 @@ impl Component for ConnectorsComponent {
     type Msg = ConnectorsMsg;
     type Props = ();
 
+    async fn init(&mut self, _props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        Ok(self.health_check_all())
+    }
+
     fn view(&mut self, f: &mut Frame, rect: Rect) {
         self.table.view(f, rect);
+
+        if let Some(form) = self.form.as_mut() {
+            let title = match form.editing.as_ref() {
+                Some(name) => format!("Edit connector {}", name),
+                None => String::from("New connector"),
+            };
+            let block = popup_block(&title);
+            let area = popup_area(f.area(), 50, 90);
+            let content = block.inner(area);
+            f.render_widget(Clear, area);
+            f.render_widget(block, area);
+            form.form.view(f, content);
+        }
+
+        if let Some(connector) = self.removing.as_ref() {
+            let area = popup_area(f.area(), 30, 15);
+            let text = Paragraph::new(vec![
+                Line::from(format!("Remove connector {}?", connector.config().name())),
+                Line::from(""),
+                Line::from("<y> confirm, <n/esc> cancel"),
+            ])
+            .centered()
+            .block(popup_block("Remove"));
+            f.render_widget(Clear, area);
+            f.render_widget(text, area);
+        }
     }
 
     async fn update(
@@ -57,8 +137,75 @@ impl Component for ConnectorsComponent {
         match msg.take() {
             ConnectorsMsg::ConnectorSelected(connector) => {
                 self.selected = Some(connector.clone());
-                Ok(ComponentReturn::action(Action::NavTo(Nav::AssetsList)))
+                if connector.status().needs_credentials() {
+                    Ok(ComponentReturn::action(Action::RequestCredentials(
+                        connector,
+                    )))
+                } else {
+                    Ok(ComponentReturn::action(Action::NavTo(Nav::AssetsList)))
+                }
+            }
+            ConnectorsMsg::RequestCredentials => match self.table.selected() {
+                Some(entry) if entry.0.config().auth().secret_alias().is_some() => Ok(
+                    ComponentReturn::action(Action::RequestCredentials(entry.0.clone())),
+                ),
+                _ => Ok(ComponentReturn::empty()),
+            },
+            ConnectorsMsg::HealthChecked(generation, connector)
+                if generation == self.generation =>
+            {
+                self.health_checked(connector)
             }
+            ConnectorsMsg::HealthChecked(..) => Ok(ComponentReturn::empty()),
+            ConnectorsMsg::SetConnectors(connectors) => Ok(self.set_connectors(connectors)),
+            ConnectorsMsg::ShowAdd => {
+                self.form = Some(ConnectorForm {
+                    editing: None,
+                    form: Self::form(None),
+                });
+                Ok(ComponentReturn::empty())
+            }
+            ConnectorsMsg::ShowEdit => {
+                self.form = self
+                    .table
+                    .selected()
+                    .map(|entry| entry.0.config())
+                    .map(|cfg| ConnectorForm {
+                        editing: Some(cfg.name().to_string()),
+                        form: Self::form(Some(cfg)),
+                    });
+                Ok(ComponentReturn::empty())
+            }
+            ConnectorsMsg::HideForm => {
+                self.form = None;
+                Ok(ComponentReturn::empty())
+            }
+            ConnectorsMsg::Form(msg) => match self.form.as_mut() {
+                Some(form) => {
+                    Self::forward_update(&mut form.form, msg.into(), Self::form_msg).await
+                }
+                None => Ok(ComponentReturn::empty()),
+            },
+            ConnectorsMsg::Save(connector) => self.save(connector),
+            ConnectorsMsg::AskRemove => {
+                self.removing = self.table.selected().map(|entry| entry.0.clone());
+                Ok(ComponentReturn::empty())
+            }
+            ConnectorsMsg::CancelRemove => {
+                self.removing = None;
+                Ok(ComponentReturn::empty())
+            }
+            ConnectorsMsg::ConfirmRemove => self.remove(),
+            ConnectorsMsg::Saved(msg, connectors) => {
+                let mut ret = self.set_connectors(connectors);
+                ret.actions
+                    .push(Action::Notification(Notification::info(msg)));
+                ret.actions.push(Action::ChangeSheet);
+                Ok(ret)
+            }
+            ConnectorsMsg::Failed(error) => Ok(ComponentReturn::action(Action::Notification(
+                Notification::error(error),
+            ))),
             ConnectorsMsg::TableEvent(table) => {
                 Self::forward_update::<_, ConnectorsTable>(
                     &mut self.table,
@@ -74,6 +221,44 @@ impl Component for ConnectorsComponent {
         &mut self,
         evt: ComponentEvent,
     ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if let Some(form) = self.form.as_mut() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ConnectorsMsg::HideForm.into()])
+                }
+                evt => Self::forward_event(&mut form.form, evt, Self::form_msg),
+            };
+        }
+
+        if self.removing.is_some() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) => match key.code {
+                    KeyCode::Char('y') => Ok(vec![ConnectorsMsg::ConfirmRemove.into()]),
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        Ok(vec![ConnectorsMsg::CancelRemove.into()])
+                    }
+                    _ => Ok(vec![]),
+                },
+                _ => Ok(vec![]),
+            };
+        }
+
+        if let ComponentEvent::Event(Event::Key(key)) = &evt {
+            match key.code {
+                KeyCode::Char('a') if self.on_save.is_some() => {
+                    return Ok(vec![ConnectorsMsg::ShowAdd.into()])
+                }
+                KeyCode::Char('e') if self.on_save.is_some() => {
+                    return Ok(vec![ConnectorsMsg::ShowEdit.into()])
+                }
+                KeyCode::Char('d') if self.on_remove.is_some() => {
+                    return Ok(vec![ConnectorsMsg::AskRemove.into()])
+                }
+                KeyCode::Char('p') => return Ok(vec![ConnectorsMsg::RequestCredentials.into()]),
+                _ => {}
+            }
+        }
+
         Self::forward_event(&mut self.table, evt, |msg| match msg {
             TableMsg::Local(table) => ConnectorsMsg::TableEvent(TableMsg::Local(table)),
             TableMsg::Outer(outer) => *outer,
@@ -92,22 +277,456 @@ impl ConnectorsComponent {
             )
             .on_select(|connector| Box::new(ConnectorsMsg::ConnectorSelected(connector.0.clone()))),
             selected,
+            on_health_check: None,
+            generation: 0,
+            form: None,
+            removing: None,
+            on_save: None,
+            on_remove: None,
+        }
+    }
+
+    pub fn on_save<F, Fut>(mut self, on_save: F) -> Self
+    where
+        F: Fn(Option<String>, NewConnector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<Vec<Connector>>> + Send,
+    {
+        let handler = Arc::new(on_save);
+        self.on_save = Some(Arc::new(move |previous, connector| {
+            let inner_handler = handler.clone();
+            async move { inner_handler(previous, connector).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn on_remove<F, Fut>(mut self, on_remove: F) -> Self
+    where
+        F: Fn(Connector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<Vec<Connector>>> + Send,
+    {
+        let handler = Arc::new(on_remove);
+        self.on_remove = Some(Arc::new(move |conn| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn on_health_check<F, Fut>(mut self, on_health_check: F) -> Self
+    where
+        F: Fn(Connector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Connector> + Send,
+    {
+        let handler = Arc::new(on_health_check);
+        self.on_health_check = Some(Arc::new(move |conn| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c).await }.boxed()
+        }));
+
+        self
+    }
+
+    fn health_check(
+        &self,
+        connector: Connector,
+        delay: Duration,
+    ) -> ComponentReturn<ConnectorsMsg> {
+        match self.on_health_check.as_ref() {
+            Some(on_health_check) if connector.status().is_probeable() => {
+                let on_health_check = on_health_check.clone();
+                let generation = self.generation;
+                ComponentReturn::cmd(
+                    async move {
+                        tokio::time::sleep(delay).await;
+                        let checked = on_health_check(&connector).await;
+                        Ok(vec![
+                            ConnectorsMsg::HealthChecked(generation, checked).into()
+                        ])
+                    }
+                    .boxed(),
+                )
+            }
+            _ => ComponentReturn::empty(),
+        }
+    }
+
+    fn health_checked(
+        &mut self,
+        checked: Connector,
+    ) -> anyhow::Result<ComponentReturn<ConnectorsMsg>> {
+        // The connector may have changed while probing, only take over what the probe found out.
+        let merge = |current: &Connector| {
+            match (current.version(), checked.version()) {
+                (None, Some(detected)) => current.with_version(detected.clone()),
+                _ => current.clone(),
+            }
+            .with_status(checked.status().clone())
+        };
+        let name = checked.config().name();
+
+        if let Some(selected) = self.selected.as_mut().filter(|c| c.config().name() == name) {
+            *selected = merge(selected);
+        }
+
+        let entry = self
+            .table
+            .elements_mut()
+            .iter_mut()
+            .find(|entry| entry.0.config().name() == name)
+            .map(|entry| {
+                entry.0 = merge(&entry.0);
+                entry.0.clone()
+            });
+
+        match entry {
+            Some(connector) => Ok(self.health_check(connector, HEALTH_CHECK_INTERVAL)),
+            None => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn form(cfg: Option<&ConnectorConfig>) -> Form<NewConnector> {
+        let auth = cfg.map(ConnectorConfig::auth);
+        let (client_id, token_url) = match auth {
+            Some(AuthKind::OAuth {
+                client_id,
+                token_url,
+                ..
+            }) => (client_id.as_str(), token_url.as_str()),
+            Some(AuthKind::Basic { username, .. }) => (username.as_str(), ""),
+            Some(AuthKind::Header { header, .. }) => (header.as_str(), ""),
+            _ => ("", ""),
+        };
+
+        let fields = [
+            ("name", "Name", cfg.map(|c| c.name())),
+            ("address", "Management address", cfg.map(|c| c.address())),
+            (
+                "api_version",
+                "API version (v3, v4 or empty to detect)",
+                cfg.and_then(|c| c.version()).map(|v| v.as_str()),
+            ),
+            (
+                "auth",
+                "Auth (no-auth, token, basic, header or oauth2)",
+                Some(auth.map(AuthKind::type_name).unwrap_or("no-auth")),
+            ),
+            (
+                "alias",
+                "Keyring alias of the token/password/client secret",
+                auth.and_then(AuthKind::secret_alias),
+            ),
+            (
+                "client_id",
+                "OAuth2 client id, basic auth username or header name",
+                Some(client_id),
+            ),
+            ("token_url", "OAuth2 token url", Some(token_url)),
+            (
+                "participant_context_id",
+                "Participant context (optional)",
+                cfg.and_then(|c| c.participant_context_id())
+                    .map(String::as_str),
+            ),
+        ];
+
+        let form = fields.into_iter().enumerate().fold(
+            Form::default(),
+            |form, (idx, (name, label, value))| {
+                form.field(
+                    TextField::builder()
+                        .name(name.to_string())
+                        .label(label.to_string())
+                        .initial_value(value.unwrap_or_default().to_string())
+                        .selected(idx == 0)
+                        .build()
+                        .unwrap(),
+                )
+            },
+        );
+
+        let secret_label = if cfg.is_some() {
+            "Token/password/client secret (empty keeps the stored one)"
+        } else {
+            "Token/password/client secret"
+        };
+
+        form.field(
+            TextField::builder()
+                .name("secret".to_string())
+                .label(secret_label.to_string())
+                .masked(true)
+                .build()
+                .unwrap(),
+        )
+        .on_confirm(Self::parse_fields)
+    }
+
+    fn parse_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<NewConnector> {
+        let field = |name: &str| -> anyhow::Result<String> {
+            let value: String = fields[name].clone().try_into()?;
+            Ok(value.trim().to_string())
+        };
+
+        let alias = field("alias")?;
+        let auth = match field("auth")?.to_lowercase().as_str() {
+            "" | "no-auth" => AuthKind::NoAuth,
+            "token" => AuthKind::Token { token_alias: alias },
+            "basic" => AuthKind::Basic {
+                username: field("client_id")?,
+                password_alias: alias,
+            },
+            "header" => AuthKind::Header {
+                header: field("client_id")?,
+                token_alias: alias,
+            },
+            "oauth2" => AuthKind::OAuth {
+                client_id: field("client_id")?,
+                token_url: field("token_url")?,
+                secret_alias: alias,
+                options: Default::default(),
+            },
+            other => anyhow::bail!(
+                "Unknown auth {}, expected no-auth, token, basic, header or oauth2",
+                other
+            ),
+        };
+
+        let config = ConnectorConfig::new(field("name")?, field("address")?, auth)
+            .with_api_version(ConnectorApiVersion::parse(&field("api_version")?)?)
+            .with_participant_context_id(
+                Some(field("participant_context_id")?).filter(|ctx| !ctx.is_empty()),
+            );
+
+        let errors = config.validate();
+        if !errors.is_empty() {
+            anyhow::bail!("{}", errors.join(", "));
+        }
+
+        let secret: String = fields["secret"].clone().try_into()?;
+        Ok(NewConnector {
+            config,
+            secret: Some(secret).filter(|s| !s.is_empty()),
+        })
+    }
+
+    fn form_msg(msg: FormMsg<NewConnector>) -> ConnectorsMsg {
+        match msg {
+            FormMsg::Local(local) => ConnectorsMsg::Form(FormMsg::Local(local)),
+            FormMsg::Outer(connector) => ConnectorsMsg::Save(connector),
+        }
+    }
+
+    fn save(&mut self, connector: NewConnector) -> anyhow::Result<ComponentReturn<ConnectorsMsg>> {
+        match (self.form.take(), self.on_save.clone()) {
+            (Some(form), Some(on_save)) => Ok(ComponentReturn::cmd(
+                async move {
+                    let name = connector.config.name().to_string();
+                    let msg = match on_save(form.editing, connector).await {
+                        Ok(connectors) => {
+                            ConnectorsMsg::Saved(format!("Connector {} saved", name), connectors)
+                        }
+                        Err(err) => ConnectorsMsg::Failed(err.to_string()),
+                    };
+                    Ok(vec![msg.into()])
+                }
+                .boxed(),
+            )),
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn remove(&mut self) -> anyhow::Result<ComponentReturn<ConnectorsMsg>> {
+        match (self.removing.take(), self.on_remove.clone()) {
+            (Some(connector), Some(on_remove)) => Ok(ComponentReturn::cmd(
+                async move {
+                    let msg = match on_remove(&connector).await {
+                        Ok(connectors) => ConnectorsMsg::Saved(
+                            format!("Connector {} removed", connector.config().name()),
+                            connectors,
+                        ),
+                        Err(err) => ConnectorsMsg::Failed(err.to_string()),
+                    };
+                    Ok(vec![msg.into()])
+                }
+                .boxed(),
+            )),
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    /// Replaces the connector with the same name, e.g. after its credentials changed.
+    pub fn replace_connector(&mut self, connector: Connector) -> ComponentReturn<ConnectorsMsg> {
+        let name = connector.config().name();
+        if let Some(selected) = self.selected.as_mut().filter(|c| c.config().name() == name) {
+            *selected = connector.clone();
+        }
+
+        if let Some(entry) = self
+            .table
+            .elements_mut()
+            .iter_mut()
+            .find(|entry| entry.0.config().name() == name)
+        {
+            entry.0 = connector;
+        }
+
+        // Probes still running with the old credentials must not overwrite the new status.
+        self.generation += 1;
+        self.health_check_all()
+    }
+
+    /// Replaces the connectors, e.g. after the configuration changed. Connectors with an
+    /// unchanged configuration keep their runtime state and the selection is kept by name.
+    fn set_connectors(&mut self, connectors: Vec<Connector>) -> ComponentReturn<ConnectorsMsg> {
+        let connectors: Vec<Connector> = connectors
+            .into_iter()
+            .map(|connector| {
+                self.table
+                    .elements()
+                    .iter()
+                    .find(|entry| entry.0.config() == connector.config())
+                    .map(|entry| entry.0.clone())
+                    .unwrap_or(connector)
+            })
+            .collect();
+
+        let selected_name = self
+            .selected
+            .as_ref()
+            .map(|c| c.config().name().to_string());
+        let selected_idx = selected_name
+            .and_then(|name| connectors.iter().position(|c| c.config().name() == name))
+            .unwrap_or(0);
+
+        self.selected = connectors.get(selected_idx).cloned();
+        self.table
+            .update_elements(connectors.into_iter().map(ConnectorEntry).collect());
+        self.table.select(selected_idx);
+
+        // Invalidate the running health check loops and start new ones.
+        self.generation += 1;
+        self.health_check_all()
+    }
+
+    fn health_check_all(&self) -> ComponentReturn<ConnectorsMsg> {
+        let mut ret = ComponentReturn::empty();
+        for entry in self.table.elements() {
+            ret.cmds
+                .extend(self.health_check(entry.0.clone(), Duration::ZERO).cmds);
+        }
+        ret
+    }
+
+    pub fn set_participant_context(&mut self, ctx: Option<String>) {
+        if let Some(selected) = self.selected.as_mut() {
+            *selected = selected.with_participant_context(ctx);
+            let name = selected.config().name();
+            if let Some(entry) = self
+                .table
+                .elements_mut()
+                .iter_mut()
+                .find(|entry| entry.0.config().name() == name)
+            {
+                entry.0 = selected.clone();
+            }
         }
     }
 
+    pub fn connectors(&self) -> Vec<Connector> {
+        self.table
+            .elements()
+            .iter()
+            .map(|entry| entry.0.clone())
+            .collect()
+    }
+
     pub fn selected(&self) -> Option<&Connector> {
         self.selected.as_ref()
     }
 
+    pub fn key_bindings(&self) -> InfoSheet {
+        let sheet = InfoSheet::default().key_binding("<p>", "Enter credentials");
+        let sheet = if self.on_save.is_some() {
+            sheet
+                .key_binding("<a>", "Add connector")
+                .key_binding("<e>", "Edit connector")
+        } else {
+            sheet
+        };
+        if self.on_remove.is_some() {
+            sheet.key_binding("<d>", "Remove connector")
+        } else {
+            sheet
+        }
+    }
+
     pub fn info_sheet(&self) -> InfoSheet {
         if let Some(c) = self.selected.as_ref() {
             InfoSheet::default()
                 .info("Connector Name", c.config().name())
                 .info("Connector Address", c.config().address())
+                .info("API Version", version_label(c))
+                .info("Auth", auth_label(c.config()))
+                .info(
+                    "Participant Context",
+                    c.participant_context().unwrap_or("none"),
+                )
         } else {
             InfoSheet::default()
                 .info("Connector Name", "n/a")
                 .info("Connector Address", "n/a")
+                .info("API Version", "n/a")
+                .info("Auth", "n/a")
+                .info("Participant Context", "n/a")
         }
     }
 }
+
+fn popup_block(title: &str) -> Block<'_> {
+    Block::default()
+        .title_top(
+            Line::from(Span::styled(
+                format!(" {} ", title),
+                Style::default().fg(Color::Red),
+            ))
+            .centered(),
+        )
+        .borders(Borders::ALL)
+}
+
+fn version_label(connector: &Connector) -> String {
+    match (connector.config().version(), connector.version()) {
+        (Some(configured), _) => configured.as_str().to_string(),
+        (None, Some(detected)) => format!("{} (detected)", detected.as_str()),
+        (None, None) => String::from("auto"),
+    }
+}
+
+fn auth_label(config: &ConnectorConfig) -> String {
+    let mut label = config.auth().describe();
+    if config.auth().secret_alias().is_some() {
+        label.push_str(&format!(", secret from {}", config.secret_source().name()));
+    }
+    if !config.headers().is_empty() {
+        let headers = config.headers().keys().cloned().collect::<Vec<_>>();
+        label.push_str(&format!(" + headers {}", headers.join(", ")));
+    }
+    label
+}
+
+fn status_style(status: &ConnectorStatus) -> Style {
+    match status {
+        ConnectorStatus::Unknown => Style::default().fg(Color::Gray),
+        ConnectorStatus::Connected { .. } => Style::default().fg(Color::Green),
+        ConnectorStatus::Unauthorized { .. }
+        | ConnectorStatus::AuthFailed(_)
+        | ConnectorStatus::MissingSecret(_) => Style::default().fg(Color::Yellow),
+        ConnectorStatus::Failed { .. }
+        | ConnectorStatus::Unreachable(_)
+        | ConnectorStatus::Custom(_) => Style::default().fg(Color::Red),
+    }
+}
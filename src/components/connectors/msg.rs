@@ -1,7 +1,24 @@
-use crate::{components::table::msg::TableMsg, types::connector::Connector};
+use crate::{
+    components::table::msg::TableMsg,
+    types::connector::{Connector, NewConnector},
+    widgets::form::msg::FormMsg,
+};
 
 #[derive(Debug)]
 pub enum ConnectorsMsg {
     TableEvent(TableMsg<Box<ConnectorsMsg>>),
     ConnectorSelected(Connector),
+    HealthChecked(u64, Connector),
+    SetConnectors(Vec<Connector>),
+    RequestCredentials,
+    ShowAdd,
+    ShowEdit,
+    HideForm,
+    Form(FormMsg<NewConnector>),
+    Save(NewConnector),
+    AskRemove,
+    CancelRemove,
+    ConfirmRemove,
+    Saved(String, Vec<Connector>),
+    Failed(String),
 }
@@ -1,5 +1,4 @@
 use edc_connector_client::types::contract_definition::ContractDefinition;
-use ratatui::widgets::Row;
 
 use super::{
     resources::{msg::ResourcesMsg, DrawableResource, Field, ResourcesComponent},
@@ -20,23 +19,23 @@ pub type ContractDefinitionsComponent =
     ResourcesComponent<ContractDefinitionEntry, ContractDefinitionEntry>;
 
 impl TableEntry for ContractDefinitionEntry {
-    fn row(&self) -> Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let asset_selector = serde_json::to_string(self.0.assets_selector()).unwrap();
-        Row::new(vec![
+        vec![
             self.0.id().to_string(),
             self.0.access_policy_id().to_string(),
             self.0.contract_policy_id().to_string(),
             asset_selector,
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "ID",
             "ACCESS_POLICY_ID",
             "CONTRACT_POLICY_ID",
             "ASSETS_SELECTOR",
-        ])
+        ]
     }
 }
 
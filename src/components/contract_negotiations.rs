@@ -1,7 +1,11 @@
-use edc_connector_client::types::contract_negotiation::ContractNegotiation;
-use ratatui::widgets::Row;
+use edc_connector_client::types::contract_negotiation::{
+    ContractNegotiation, ContractNegotiationState,
+};
 
-use crate::components::resources::FieldValue;
+use crate::{
+    components::resources::{follow::FollowState, screaming_snake_case, FieldValue},
+    types::query::StateCodes,
+};
 
 use super::{
     resources::{msg::ResourcesMsg, DrawableResource, Field, ResourcesComponent},
@@ -11,10 +15,48 @@ use super::{
 #[derive(Debug, Clone)]
 pub struct ContractNegotiationEntry(ContractNegotiation);
 
+pub const STATE_CODES: &[(&str, u32)] = &[
+    ("INITIAL", 50),
+    ("REQUESTING", 100),
+    ("REQUESTED", 200),
+    ("OFFERING", 300),
+    ("OFFERED", 400),
+    ("ACCEPTING", 700),
+    ("ACCEPTED", 800),
+    ("AGREEING", 825),
+    ("AGREED", 850),
+    ("VERIFYING", 1050),
+    ("VERIFIED", 1100),
+    ("FINALIZING", 1150),
+    ("FINALIZED", 1200),
+    ("TERMINATING", 1300),
+    ("TERMINATED", 1400),
+];
+
 impl ContractNegotiationEntry {
     pub fn new(contract_negotiation: ContractNegotiation) -> Self {
         Self(contract_negotiation)
     }
+
+    pub fn follow_state(&self) -> FollowState {
+        let terminal = matches!(
+            self.0.state(),
+            ContractNegotiationState::Finalized | ContractNegotiationState::Terminated
+        );
+        FollowState::new(
+            self.0.id(),
+            self.state_name(),
+            self.state_timestamp(),
+            terminal,
+        )
+    }
+
+    fn state_name(&self) -> String {
+        match self.0.state() {
+            ContractNegotiationState::Other(other) => other.clone(),
+            state => screaming_snake_case(&format!("{:?}", state)),
+        }
+    }
 }
 
 pub type ContractNegotiationMsg = ResourcesMsg<ContractNegotiationEntry, ContractNegotiationEntry>;
@@ -22,9 +64,9 @@ pub type ContractNegotiationsComponent =
     ResourcesComponent<ContractNegotiationEntry, ContractNegotiationEntry>;
 
 impl TableEntry for ContractNegotiationEntry {
-    fn row(&self) -> Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let private_properties = serde_json::to_string(self.0.private_properties()).unwrap();
-        Row::new(vec![
+        vec![
             self.0.id().to_string(),
             format!("{:?}", self.0.kind()),
             format!("{:?}", self.0.state()),
@@ -32,11 +74,11 @@ impl TableEntry for ContractNegotiationEntry {
             self.0.contract_agreement_id().cloned().unwrap_or_default(),
             private_properties,
             self.0.created_at().to_string(),
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "ID",
             "TYPE",
             "STATE",
@@ -44,7 +86,7 @@ impl TableEntry for ContractNegotiationEntry {
             "CONTRACT_AGREEMENT_ID",
             "PRIVATE_PROPERTIES",
             "CREATED_AT",
-        ])
+        ]
     }
 }
 
@@ -83,4 +125,16 @@ impl DrawableResource for ContractNegotiationEntry {
             Field::string("created_at", self.0.created_at().to_string()),
         ]
     }
+
+    fn state_codes() -> StateCodes {
+        STATE_CODES
+    }
+
+    fn state(&self) -> Option<String> {
+        Some(self.state_name())
+    }
+
+    fn created_at(&self) -> Option<i64> {
+        Some(self.0.created_at())
+    }
 }
@@ -0,0 +1,398 @@
+use std::{future::Future, sync::Arc};
+
+use crossterm::event::{Event, KeyCode};
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{apply::StepOutcome, copy::CopyPlan},
+    types::{
+        api_error::ApiError,
+        connector::Connector,
+        copy::{CopyOptions, CopyRequest},
+        manifest::{ManifestKind, Plan, PlanAction},
+    },
+};
+
+use self::msg::CopyMsg;
+
+use super::{
+    popup_area,
+    table::{msg::TableMsg, TableEntry, UiTable},
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
+};
+
+pub mod msg;
+
+pub type TargetsTable = UiTable<TargetEntry, Box<CopyMsg>>;
+
+pub type OnPlanCopy = Arc<
+    dyn Fn(&Connector, CopyRequest, CopyOptions) -> BoxFuture<'static, anyhow::Result<CopyPlan>>
+        + Send
+        + Sync,
+>;
+
+pub type OnCopy =
+    Arc<dyn Fn(&Connector, Plan) -> BoxFuture<'static, Vec<StepOutcome>> + Send + Sync>;
+
+#[derive(Debug)]
+pub struct TargetEntry(Connector);
+
+impl TableEntry for TargetEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.config().name().to_string(),
+            self.0.config().address().to_string(),
+        ]
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["NAME", "ADDRESS"]
+    }
+}
+
+/// Popup copying a resource to another configured connector: the target and options are
+/// chosen first, then the plan is reviewed before being applied.
+#[derive(Default)]
+pub struct CopyComponent {
+    state: Option<CopyState>,
+    on_plan: Option<OnPlanCopy>,
+    on_copy: Option<OnCopy>,
+}
+
+struct CopyState {
+    request: CopyRequest,
+    targets: TargetsTable,
+    options: CopyOptions,
+    review: Option<(Connector, CopyPlan)>,
+    busy: bool,
+}
+
+impl CopyComponent {
+    pub fn on_plan<F, Fut>(mut self, on_plan: F) -> Self
+    where
+        F: Fn(Connector, CopyRequest, CopyOptions) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<CopyPlan>> + Send,
+    {
+        let handler = Arc::new(on_plan);
+        self.on_plan = Some(Arc::new(move |conn, request, options| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, request, options).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn on_copy<F, Fut>(mut self, on_copy: F) -> Self
+    where
+        F: Fn(Connector, Plan) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<StepOutcome>> + Send,
+    {
+        let handler = Arc::new(on_copy);
+        self.on_copy = Some(Arc::new(move |conn, plan| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, plan).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.state.is_some()
+    }
+
+    fn plan(&mut self) -> anyhow::Result<ComponentReturn<CopyMsg>> {
+        let Some(state) = self.state.as_mut() else {
+            return Ok(ComponentReturn::empty());
+        };
+        match (state.targets.selected(), self.on_plan.clone()) {
+            (Some(target), Some(on_plan)) => {
+                let target = target.0.clone();
+                let request = state.request.clone();
+                let options = state.options;
+                state.busy = true;
+                Ok(ComponentReturn::cmd(
+                    async move {
+                        match on_plan(&target, request, options).await {
+                            Ok(plan) => Ok(vec![CopyMsg::Planned(target, plan).into()]),
+                            Err(err) => Ok(vec![CopyMsg::Failed(ApiError::new(&err)).into()]),
+                        }
+                    }
+                    .boxed(),
+                ))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn copy(&mut self) -> anyhow::Result<ComponentReturn<CopyMsg>> {
+        let Some(state) = self.state.as_mut() else {
+            return Ok(ComponentReturn::empty());
+        };
+        match (state.review.clone(), self.on_copy.clone()) {
+            (Some((target, plan)), Some(on_copy)) if !plan.plan.is_empty() => {
+                state.busy = true;
+                Ok(ComponentReturn::cmd(
+                    async move {
+                        let outcomes = on_copy(&target, plan.plan).await;
+                        Ok(vec![CopyMsg::Copied(target, outcomes).into()])
+                    }
+                    .boxed(),
+                ))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn copied(
+        &mut self,
+        target: Connector,
+        outcomes: Vec<StepOutcome>,
+    ) -> anyhow::Result<ComponentReturn<CopyMsg>> {
+        self.state = None;
+        let failures = outcomes
+            .iter()
+            .filter_map(|outcome| {
+                outcome
+                    .error
+                    .as_ref()
+                    .map(|error| format!("{} {}: {}", outcome.step.kind, outcome.step.id, error))
+            })
+            .collect::<Vec<_>>();
+
+        let name = target.config().name();
+        let noty = match failures.first() {
+            None => {
+                Notification::info(format!("Copied {} resource(s) to {}", outcomes.len(), name))
+            }
+            Some(first) => Notification::error(format!(
+                "Copied {} of {} resource(s) to {}, failed {}",
+                outcomes.len() - failures.len(),
+                outcomes.len(),
+                name,
+                first
+            )),
+        };
+        Ok(ComponentReturn::action(Action::Notification(noty)))
+    }
+
+    fn view_targets(state: &mut CopyState, f: &mut Frame, area: Rect) {
+        let [table_area, options_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(4)]).areas(area);
+        state.targets.view(f, table_area);
+
+        let mut lines = vec![];
+        if state.request.kind == ManifestKind::ContractDefinition {
+            lines.push(Line::from(format!(
+                "<d> with policies and selected assets: {}",
+                if state.options.dependencies {
+                    "yes"
+                } else {
+                    "no"
+                }
+            )));
+        }
+        lines.push(Line::from(format!(
+            "<m> on conflict: {}",
+            state.options.conflicts.label()
+        )));
+        lines.push(Line::from("<enter> review, <esc> cancel"));
+        let p = Paragraph::new(lines).block(Block::default().borders(Borders::TOP));
+        f.render_widget(p, options_area);
+    }
+
+    fn view_review(target: &Connector, plan: &CopyPlan, f: &mut Frame, area: Rect) {
+        let mut lines = vec![Line::styled(
+            format!("Copy to {}:", target.config().name()),
+            Style::default().fg(Color::Cyan),
+        )];
+        for step in plan.plan.steps() {
+            let (sign, color) = match step.action {
+                PlanAction::Create => ("+", Color::Green),
+                PlanAction::Update => ("~", Color::Yellow),
+                PlanAction::Delete => ("-", Color::Red),
+            };
+            let renamed = plan
+                .renamed
+                .iter()
+                .find(|(kind, _, id)| *kind == step.kind && *id == step.id)
+                .map(|(_, from, _)| format!(" (renamed from {})", from))
+                .unwrap_or_default();
+            lines.push(Line::styled(
+                format!("{} {} {}{}", sign, step.kind, step.id, renamed),
+                Style::default().fg(color),
+            ));
+        }
+        for step in plan.skipped.iter() {
+            lines.push(Line::from(format!(
+                "  skip {} {}, differs in {}",
+                step.kind,
+                step.id,
+                step.changes.join(", ")
+            )));
+        }
+        if plan.unchanged > 0 {
+            lines.push(Line::from(format!(
+                "  {} resource(s) already up to date",
+                plan.unchanged
+            )));
+        }
+        lines.push(Line::from(""));
+        if plan.plan.is_empty() {
+            lines.push(Line::from("Nothing to copy, <esc> back"));
+        } else {
+            lines.push(Line::from("<y> copy, <esc> back"));
+        }
+
+        let p = Paragraph::new(lines).wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for CopyComponent {
+    type Msg = CopyMsg;
+    type Props = (CopyRequest, Vec<Connector>);
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        let (request, connectors) = props;
+        let source = request.source.config().name().to_string();
+        let targets = connectors
+            .into_iter()
+            .filter(|connector| connector.config().name() != source)
+            .map(TargetEntry)
+            .collect::<Vec<_>>();
+
+        if targets.is_empty() {
+            self.state = None;
+            return Ok(ComponentReturn::action(Action::Notification(
+                Notification::error("No other connector to copy to".to_string()),
+            )));
+        }
+
+        let mut table = TargetsTable::new("Targets".to_string());
+        table.update_elements(targets);
+        table.select(0);
+        self.state = Some(CopyState {
+            request,
+            targets: table,
+            options: CopyOptions::default(),
+            review: None,
+            busy: false,
+        });
+        Ok(ComponentReturn::empty())
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let Some(state) = self.state.as_mut() else {
+            return;
+        };
+        let title = format!(
+            " Copy {} {} from {}{} ",
+            state.request.kind,
+            state.request.id,
+            state.request.source.config().name(),
+            if state.busy { " (working...)" } else { "" }
+        );
+        let block = Block::default()
+            .title_top(Line::from(Span::styled(title, Style::default().fg(Color::Red))).centered())
+            .borders(Borders::ALL);
+        let area = popup_area(rect, 60, 50);
+        let content = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        match state.review.as_ref() {
+            Some((target, plan)) => Self::view_review(target, plan, f, content),
+            None => Self::view_targets(state, f, content),
+        }
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        let msg = msg.take();
+        let Some(state) = self.state.as_mut() else {
+            return Ok(ComponentReturn::empty());
+        };
+        match msg {
+            CopyMsg::TableEvent(table) => {
+                Self::forward_update(&mut state.targets, table.into(), CopyMsg::TableEvent).await
+            }
+            CopyMsg::ToggleDependencies => {
+                state.options.dependencies = !state.options.dependencies;
+                Ok(ComponentReturn::empty())
+            }
+            CopyMsg::NextConflictMode => {
+                state.options.conflicts = state.options.conflicts.next();
+                Ok(ComponentReturn::empty())
+            }
+            CopyMsg::Plan => self.plan(),
+            CopyMsg::Planned(target, plan) => {
+                state.busy = false;
+                state.review = Some((target, plan));
+                Ok(ComponentReturn::empty())
+            }
+            CopyMsg::Back => {
+                state.review = None;
+                Ok(ComponentReturn::empty())
+            }
+            CopyMsg::Confirm => self.copy(),
+            CopyMsg::Copied(target, outcomes) => self.copied(target, outcomes),
+            CopyMsg::Failed(error) => {
+                state.busy = false;
+                Ok(ComponentReturn::action(Action::Error(error)))
+            }
+            CopyMsg::Cancel => {
+                self.state = None;
+                Ok(ComponentReturn::empty())
+            }
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        let Some(state) = self.state.as_mut() else {
+            return Ok(vec![]);
+        };
+        let ComponentEvent::Event(Event::Key(key)) = &evt else {
+            return Ok(vec![]);
+        };
+
+        if state.busy {
+            return Ok(vec![]);
+        }
+
+        if state.review.is_some() {
+            return match key.code {
+                KeyCode::Char('y') => Ok(vec![CopyMsg::Confirm.into()]),
+                KeyCode::Char('n') | KeyCode::Esc => Ok(vec![CopyMsg::Back.into()]),
+                _ => Ok(vec![]),
+            };
+        }
+
+        match key.code {
+            KeyCode::Esc => Ok(vec![CopyMsg::Cancel.into()]),
+            KeyCode::Enter => Ok(vec![CopyMsg::Plan.into()]),
+            KeyCode::Char('m') => Ok(vec![CopyMsg::NextConflictMode.into()]),
+            KeyCode::Char('d') if state.request.kind == ManifestKind::ContractDefinition => {
+                Ok(vec![CopyMsg::ToggleDependencies.into()])
+            }
+            _ => Self::forward_event(&mut state.targets, evt, |msg| match msg {
+                TableMsg::Local(table) => CopyMsg::TableEvent(TableMsg::Local(table)),
+                TableMsg::Outer(outer) => *outer,
+            }),
+        }
+    }
+}
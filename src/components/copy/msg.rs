@@ -0,0 +1,19 @@
+use crate::{
+    app::{apply::StepOutcome, copy::CopyPlan},
+    components::table::msg::TableMsg,
+    types::{api_error::ApiError, connector::Connector},
+};
+
+#[derive(Debug)]
+pub enum CopyMsg {
+    TableEvent(TableMsg<Box<CopyMsg>>),
+    ToggleDependencies,
+    NextConflictMode,
+    Plan,
+    Planned(Connector, CopyPlan),
+    Back,
+    Confirm,
+    Copied(Connector, Vec<StepOutcome>),
+    Failed(ApiError),
+    Cancel,
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+
+use crossterm::event::{Event, KeyCode};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear},
+    Frame,
+};
+
+use crate::{
+    config::{AuthKind, OAuthClientAuth},
+    types::connector::{Connector, Credentials},
+    widgets::form::{msg::FormMsg, text::TextField, FieldComponent, Form},
+};
+
+use self::msg::CredentialsMsg;
+
+use super::{popup_area, Action, Component, ComponentEvent, ComponentMsg, ComponentReturn};
+
+pub mod msg;
+
+/// Prompt for the secret of a connector, masked except for private key file paths.
+#[derive(Default)]
+pub struct CredentialsComponent {
+    prompt: Option<(Connector, Form<Credentials>)>,
+}
+
+impl CredentialsComponent {
+    pub fn is_visible(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    fn form(connector: &Connector) -> Form<Credentials> {
+        // A PEM key doesn't fit a single line field, so it is read from a file.
+        let (name, label, masked) = match connector.config().auth() {
+            AuthKind::OAuth { options, .. }
+                if options.client_auth == OAuthClientAuth::PrivateKeyJwt =>
+            {
+                ("key_file", "Private key file (PEM)", false)
+            }
+            AuthKind::OAuth { .. } => ("secret", "Client secret", true),
+            AuthKind::Basic { .. } => ("secret", "Password", true),
+            _ => ("secret", "Token", true),
+        };
+
+        let form = Form::default().field(
+            TextField::builder()
+                .name(name.to_string())
+                .label(label.to_string())
+                .masked(masked)
+                .selected(true)
+                .build()
+                .unwrap(),
+        );
+
+        // Secrets from other sources can only be replaced for the session.
+        let form = if connector.config().secret_source().is_keyring() {
+            form.field(
+                TextField::builder()
+                    .name("save".to_string())
+                    .label("Save to keyring (y/n)".to_string())
+                    .initial_value("y".to_string())
+                    .build()
+                    .unwrap(),
+            )
+        } else {
+            form
+        };
+
+        form.on_confirm(Self::parse_fields)
+    }
+
+    fn parse_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<Credentials> {
+        let secret: String = match fields.get("key_file") {
+            Some(field) => {
+                let path: String = field.clone().try_into()?;
+                let path = path.trim();
+                if path.is_empty() {
+                    anyhow::bail!("The key file must not be empty");
+                }
+                std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?
+            }
+            None => fields["secret"].clone().try_into()?,
+        };
+        let save: String = match fields.get("save") {
+            Some(field) => field.clone().try_into()?,
+            None => String::new(),
+        };
+
+        if secret.is_empty() {
+            anyhow::bail!("The secret must not be empty");
+        }
+
+        let save = match save.trim().to_lowercase().as_str() {
+            "y" | "yes" => true,
+            "n" | "no" | "" => false,
+            other => anyhow::bail!("Expected y or n, got {}", other),
+        };
+
+        Ok(Credentials { secret, save })
+    }
+
+    fn form_msg(msg: FormMsg<Credentials>) -> CredentialsMsg {
+        match msg {
+            FormMsg::Local(local) => CredentialsMsg::Form(FormMsg::Local(local)),
+            FormMsg::Outer(credentials) => CredentialsMsg::Submit(credentials),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for CredentialsComponent {
+    type Msg = CredentialsMsg;
+    type Props = Connector;
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        let form = Self::form(&props);
+        self.prompt = Some((props, form));
+        Ok(ComponentReturn::empty())
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        if let Some((connector, form)) = self.prompt.as_mut() {
+            let alias = connector.config().auth().secret_alias().unwrap_or_default();
+            let block = Block::default()
+                .title_top(
+                    Line::from(Span::styled(
+                        format!(
+                            " Credentials for {} ({}) ",
+                            connector.config().name(),
+                            alias
+                        ),
+                        Style::default().fg(Color::Red),
+                    ))
+                    .centered(),
+                )
+                .borders(Borders::ALL);
+            let area = popup_area(rect, 40, 30);
+            let content = block.inner(area);
+            f.render_widget(Clear, area);
+            f.render_widget(block, area);
+            form.view(f, content);
+        }
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            CredentialsMsg::Form(msg) => match self.prompt.as_mut() {
+                Some((_, form)) => Self::forward_update(form, msg.into(), Self::form_msg).await,
+                None => Ok(ComponentReturn::empty()),
+            },
+            CredentialsMsg::Submit(credentials) => match self.prompt.take() {
+                Some((connector, _)) => Ok(ComponentReturn::action(Action::SetCredentials(
+                    connector,
+                    credentials,
+                ))),
+                None => Ok(ComponentReturn::empty()),
+            },
+            CredentialsMsg::Cancel => {
+                self.prompt = None;
+                Ok(ComponentReturn::empty())
+            }
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        match (self.prompt.as_mut(), evt) {
+            (Some(_), ComponentEvent::Event(Event::Key(key))) if key.code == KeyCode::Esc => {
+                Ok(vec![CredentialsMsg::Cancel.into()])
+            }
+            (Some((_, form)), evt) => Self::forward_event(form, evt, Self::form_msg),
+            (None, _) => Ok(vec![]),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{types::connector::Credentials, widgets::form::msg::FormMsg};
+
+#[derive(Debug)]
+pub enum CredentialsMsg {
+    Form(FormMsg<Credentials>),
+    Submit(Credentials),
+    Cancel,
+}
@@ -0,0 +1,409 @@
+use std::{future::Future, sync::Arc};
+
+use crossterm::event::{Event, KeyCode};
+use edc_connector_client::types::query::Query;
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::types::{
+    api_error::ApiError,
+    connector::{is_unauthorized, Connector},
+    info::InfoSheet,
+    nav::Nav,
+    query::state_query,
+};
+
+use self::msg::DashboardMsg;
+
+use super::{
+    contract_negotiations, transfer_processes, Action, Component, ComponentEvent, ComponentMsg,
+    ComponentReturn,
+};
+
+pub mod msg;
+
+pub type OnDashboardFetch =
+    Arc<dyn Fn(&Connector) -> BoxFuture<'static, anyhow::Result<DashboardStats>> + Send + Sync>;
+
+#[derive(Debug, Clone)]
+pub struct StateCount {
+    state: String,
+    count: u64,
+}
+
+impl StateCount {
+    pub fn new(state: String, count: u64) -> Self {
+        Self { state, count }
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DashboardStats {
+    pub assets: u64,
+    pub policies: u64,
+    pub contract_definitions: u64,
+    pub contract_agreements: u64,
+    pub contract_negotiations: Vec<StateCount>,
+    pub transfer_processes: Vec<StateCount>,
+}
+
+impl DashboardStats {
+    fn cards(&self) -> Vec<(&'static str, u64, Nav)> {
+        vec![
+            ("Assets", self.assets, Nav::AssetsList),
+            ("Policies", self.policies, Nav::PoliciesList),
+            (
+                "Contract Definitions",
+                self.contract_definitions,
+                Nav::ContractDefinitionsList,
+            ),
+            (
+                "Contract Agreements",
+                self.contract_agreements,
+                Nav::ContractAgreements,
+            ),
+            (
+                "Contract Negotiations",
+                Self::total(&self.contract_negotiations),
+                Nav::ContractNegotiations,
+            ),
+            (
+                "Transfer Processes",
+                Self::total(&self.transfer_processes),
+                Nav::TransferProcesses,
+            ),
+        ]
+    }
+
+    fn total(counts: &[StateCount]) -> u64 {
+        counts.iter().map(StateCount::count).sum()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Panel {
+    #[default]
+    Cards,
+    ContractNegotiations,
+    TransferProcesses,
+}
+
+impl Panel {
+    fn next(self) -> Self {
+        match self {
+            Panel::Cards => Panel::ContractNegotiations,
+            Panel::ContractNegotiations => Panel::TransferProcesses,
+            Panel::TransferProcesses => Panel::Cards,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            Panel::Cards => Panel::TransferProcesses,
+            Panel::ContractNegotiations => Panel::Cards,
+            Panel::TransferProcesses => Panel::ContractNegotiations,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DashboardComponent {
+    connector: Option<Connector>,
+    stats: Option<DashboardStats>,
+    panel: Panel,
+    selected: usize,
+    on_fetch: Option<OnDashboardFetch>,
+}
+
+impl DashboardComponent {
+    pub fn on_fetch<F, Fut>(mut self, on_fetch: F) -> Self
+    where
+        F: Fn(Connector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<DashboardStats>> + Send,
+    {
+        let handler = Arc::new(on_fetch);
+        self.on_fetch = Some(Arc::new(move |conn| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn info_sheet(&self) -> InfoSheet {
+        InfoSheet::default()
+            .key_binding("<h/l>", "Switch panel")
+            .key_binding("<j/k>", "Move selection")
+            .key_binding("<enter>", "Show list")
+            .key_binding("<r>", "Refresh")
+    }
+
+    fn fetch(&self) -> anyhow::Result<ComponentReturn<DashboardMsg>> {
+        if let (Some(connector), Some(on_fetch)) = (self.connector.as_ref(), self.on_fetch.as_ref())
+        {
+            let connector = connector.clone();
+            let on_fetch = on_fetch.clone();
+            Ok(ComponentReturn::cmd(
+                async move {
+                    match on_fetch(&connector).await {
+                        Ok(stats) => Ok(vec![DashboardMsg::StatsFetched(stats).into()]),
+                        Err(err) if is_unauthorized(&err) => Ok(vec![
+                            DashboardMsg::FetchFailed(ApiError::new(&err)).into(),
+                            DashboardMsg::Unauthorized.into(),
+                        ]),
+                        Err(err) => Ok(vec![DashboardMsg::FetchFailed(ApiError::new(&err)).into()]),
+                    }
+                }
+                .boxed(),
+            ))
+        } else {
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn panel_len(&self) -> usize {
+        match (self.stats.as_ref(), self.panel) {
+            (None, _) => 0,
+            (Some(stats), Panel::Cards) => stats.cards().len(),
+            (Some(stats), Panel::ContractNegotiations) => stats.contract_negotiations.len(),
+            (Some(stats), Panel::TransferProcesses) => stats.transfer_processes.len(),
+        }
+    }
+
+    fn drill_down(&self) -> Option<Action> {
+        let stats = self.stats.as_ref()?;
+        match self.panel {
+            Panel::Cards => stats
+                .cards()
+                .into_iter()
+                .nth(self.selected)
+                .map(|(_, _, nav)| Action::DrillDown(nav, Query::default())),
+            Panel::ContractNegotiations => {
+                stats.contract_negotiations.get(self.selected).map(|count| {
+                    Action::DrillDown(
+                        Nav::ContractNegotiations,
+                        state_query(count.state(), contract_negotiations::STATE_CODES),
+                    )
+                })
+            }
+            Panel::TransferProcesses => stats.transfer_processes.get(self.selected).map(|count| {
+                Action::DrillDown(
+                    Nav::TransferProcesses,
+                    state_query(count.state(), transfer_processes::STATE_CODES),
+                )
+            }),
+        }
+    }
+
+    fn panel_block(&self, title: &str, panel: Panel) -> Block<'_> {
+        let color = if self.panel == panel {
+            Color::Yellow
+        } else {
+            Color::Cyan
+        };
+        Block::default()
+            .title_top(
+                Line::from(Span::styled(
+                    format!(" {} ", title),
+                    Style::default().fg(color),
+                ))
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+    }
+
+    fn render_cards(&self, f: &mut Frame, area: Rect, stats: &DashboardStats) {
+        let block = self.panel_block("Summary", Panel::Cards);
+        let content = block.inner(area);
+        f.render_widget(block, area);
+
+        let cards = stats.cards();
+        let areas = Layout::horizontal(vec![Constraint::Ratio(1, cards.len() as u32); cards.len()])
+            .split(content);
+
+        for (idx, ((title, count, _), card)) in cards.into_iter().zip(areas.iter()).enumerate() {
+            let style = if self.panel == Panel::Cards && self.selected == idx {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let paragraph = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    count.to_string(),
+                    style.add_modifier(Modifier::BOLD),
+                )),
+                Line::from(Span::styled(title, style)),
+            ])
+            .centered()
+            .block(Block::default().borders(Borders::ALL).border_style(style));
+            f.render_widget(paragraph, *card);
+        }
+    }
+
+    fn render_chart(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        title: &str,
+        panel: Panel,
+        counts: &[StateCount],
+    ) {
+        let block = self.panel_block(title, panel);
+
+        if counts.is_empty() {
+            f.render_widget(Paragraph::new("No entries").centered().block(block), area);
+            return;
+        }
+
+        let bars = counts
+            .iter()
+            .enumerate()
+            .map(|(idx, count)| {
+                let style = if self.panel == panel && self.selected == idx {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::Cyan)
+                };
+                Bar::default()
+                    .value(count.count())
+                    .label(Line::from(count.state().to_string()))
+                    .text_value(count.count().to_string())
+                    .style(style)
+                    .value_style(style.add_modifier(Modifier::REVERSED))
+            })
+            .collect::<Vec<_>>();
+
+        let chart = BarChart::default()
+            .block(block)
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(1)
+            .data(BarGroup::default().bars(&bars));
+
+        f.render_widget(chart, area);
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for DashboardComponent {
+    type Msg = DashboardMsg;
+    type Props = Connector;
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        self.connector = Some(props);
+        self.stats = None;
+        self.selected = 0;
+        self.fetch()
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let Some(stats) = self.stats.as_ref() else {
+            let loading = Paragraph::new("Loading...")
+                .centered()
+                .block(self.panel_block("Dashboard", self.panel));
+            f.render_widget(loading, rect);
+            return;
+        };
+
+        let [cards, charts] =
+            Layout::vertical([Constraint::Length(6), Constraint::Min(1)]).areas(rect);
+        let [negotiations, transfers] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(charts);
+
+        self.render_cards(f, cards, stats);
+        self.render_chart(
+            f,
+            negotiations,
+            "Contract Negotiations",
+            Panel::ContractNegotiations,
+            &stats.contract_negotiations,
+        );
+        self.render_chart(
+            f,
+            transfers,
+            "Transfer Processes",
+            Panel::TransferProcesses,
+            &stats.transfer_processes,
+        );
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            DashboardMsg::StatsFetched(stats) => {
+                self.stats = Some(stats);
+                self.selected = self.selected.min(self.panel_len().saturating_sub(1));
+                Ok(ComponentReturn::empty())
+            }
+            DashboardMsg::FetchFailed(error) => Ok(ComponentReturn::action(Action::Error(error))),
+            DashboardMsg::Unauthorized => match self.connector.clone() {
+                Some(connector) => Ok(ComponentReturn::action(Action::RequestCredentials(
+                    connector,
+                ))),
+                None => Ok(ComponentReturn::empty()),
+            },
+            DashboardMsg::Refresh => self.fetch(),
+            DashboardMsg::NextPanel => {
+                self.panel = self.panel.next();
+                self.selected = 0;
+                Ok(ComponentReturn::empty())
+            }
+            DashboardMsg::PrevPanel => {
+                self.panel = self.panel.prev();
+                self.selected = 0;
+                Ok(ComponentReturn::empty())
+            }
+            DashboardMsg::NextItem => {
+                if self.selected + 1 < self.panel_len() {
+                    self.selected += 1;
+                }
+                Ok(ComponentReturn::empty())
+            }
+            DashboardMsg::PrevItem => {
+                self.selected = self.selected.saturating_sub(1);
+                Ok(ComponentReturn::empty())
+            }
+            DashboardMsg::DrillDown => match self.drill_down() {
+                Some(action) => Ok(ComponentReturn::action(action)),
+                None => Ok(ComponentReturn::empty()),
+            },
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        match evt {
+            ComponentEvent::Event(Event::Key(key)) => match key.code {
+                KeyCode::Char('l') | KeyCode::Right => Ok(vec![DashboardMsg::NextPanel.into()]),
+                KeyCode::Char('h') | KeyCode::Left => Ok(vec![DashboardMsg::PrevPanel.into()]),
+                KeyCode::Char('j') | KeyCode::Down => Ok(vec![DashboardMsg::NextItem.into()]),
+                KeyCode::Char('k') | KeyCode::Up => Ok(vec![DashboardMsg::PrevItem.into()]),
+                KeyCode::Char('r') => Ok(vec![DashboardMsg::Refresh.into()]),
+                KeyCode::Enter => Ok(vec![DashboardMsg::DrillDown.into()]),
+                _ => Ok(vec![]),
+            },
+            _ => Ok(vec![]),
+        }
+    }
+}
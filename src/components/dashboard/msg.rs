@@ -0,0 +1,16 @@
+use crate::types::api_error::ApiError;
+
+use super::DashboardStats;
+
+#[derive(Debug)]
+pub enum DashboardMsg {
+    StatsFetched(DashboardStats),
+    FetchFailed(ApiError),
+    Unauthorized,
+    Refresh,
+    NextPanel,
+    PrevPanel,
+    NextItem,
+    PrevItem,
+    DrillDown,
+}
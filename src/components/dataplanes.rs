@@ -1,5 +1,4 @@
 use edc_connector_client::types::dataplane::DataPlaneInstance;
-use ratatui::widgets::Row;
 
 use crate::components::resources::FieldValue;
 
@@ -21,24 +20,24 @@ pub type DataPlaneMsg = ResourcesMsg<DataPlaneEntry, DataPlaneEntry>;
 pub type DataPlanesComponent = ResourcesComponent<DataPlaneEntry, DataPlaneEntry>;
 
 impl TableEntry for DataPlaneEntry {
-    fn row(&self) -> Row<'_> {
-        Row::new(vec![
+    fn cells(&self) -> Vec<String> {
+        vec![
             self.0.id().to_string(),
             format!("{:}", self.0.url()),
             format!("{:?}", self.0.state()),
             format!("{:?}", self.0.allowed_transfer_types()),
             format!("{:?}", self.0.allowed_source_types()),
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "ID",
             "URL",
             "STATE",
             "ALLOWED_TRANSFER_TYPES",
             "ALLOWED_SOURCE_TYPES",
-        ])
+        ]
     }
 }
 
@@ -5,7 +5,6 @@ use super::{
     table::TableEntry,
 };
 use edc_connector_client::types::{data_address::DataAddress, edr::EndpointDataReferenceEntry};
-use ratatui::widgets::Row;
 
 pub type EdrsMsg = ResourcesMsg<EdrMetadataEntry, EdrEntry>;
 pub type EdrsComponent = ResourcesComponent<EdrMetadataEntry, EdrEntry>;
@@ -20,15 +19,11 @@ impl EdrMetadataEntry {
     pub fn new(edr_entry: EndpointDataReferenceEntry) -> EdrMetadataEntry {
         EdrMetadataEntry(edr_entry)
     }
-
-    pub fn id(&self) -> &str {
-        self.0.transfer_process_id()
-    }
 }
 
 impl TableEntry for EdrMetadataEntry {
-    fn row(&self) -> Row<'_> {
-        Row::new(vec![
+    fn cells(&self) -> Vec<String> {
+        vec![
             self.0.transfer_process_id().to_string(),
             self.0.asset_id().to_string(),
             self.0.agreement_id().to_string(),
@@ -38,18 +33,49 @@ impl TableEntry for EdrMetadataEntry {
                 .map(String::to_string)
                 .unwrap_or_default(),
             format!("{}", self.0.created_at()),
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "TRANSFER_PROCESS_ID",
             "ASSET_ID",
             "AGREEMENT_ID",
             "PROVIDER_ID",
             "NEGOTIATION_ID",
             "CREATED_AT",
-        ])
+        ]
+    }
+}
+
+impl DrawableResource for EdrMetadataEntry {
+    fn id(&self) -> &str {
+        self.0.transfer_process_id()
+    }
+
+    fn title() -> &'static str {
+        "Edrs"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::string("transfer_process_id", self.0.transfer_process_id()),
+            Field::string("asset_id", self.0.asset_id()),
+            Field::string("agreement_id", self.0.agreement_id()),
+            Field::string("provider_id", self.0.provider_id()),
+            Field::string(
+                "contract_negotiation_id",
+                self.0
+                    .contract_negotiation_id()
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            Field::string("created_at", self.0.created_at().to_string()),
+        ]
+    }
+
+    fn created_at(&self) -> Option<i64> {
+        Some(self.0.created_at())
     }
 }
 
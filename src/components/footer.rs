@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Style},
@@ -10,10 +12,11 @@ use super::{Component, Notification, NotificationKind};
 
 pub mod msg;
 
+/// Shows one notification at a time, the others wait in a queue until it times out.
 #[derive(Default)]
 pub struct Footer {
     noty: Option<Notification>,
-    noty_counter: u32,
+    queue: VecDeque<Notification>,
 }
 
 #[async_trait::async_trait]
@@ -24,7 +27,11 @@ impl Component for Footer {
     fn view(&mut self, f: &mut Frame, rect: Rect) {
         let block = Block::default().borders(Borders::all());
 
-        let content = self.noty.as_ref().map(Notification::msg).unwrap_or("");
+        let content = match (self.noty.as_ref(), self.queue.len()) {
+            (Some(noty), 0) => noty.msg().to_string(),
+            (Some(noty), queued) => format!("{} (+{} queued)", noty.msg(), queued),
+            (None, _) => String::new(),
+        };
 
         let style = self
             .noty
@@ -41,18 +48,33 @@ impl Component for Footer {
 }
 
 impl Footer {
-    pub fn show_notification(&mut self, noty: Notification) {
-        self.noty = Some(noty);
-        self.noty_counter += 1;
-    }
-    pub fn clear_notification(&mut self) {
-        self.noty_counter -= 1;
+    /// Returns the timeout to schedule when the notification is shown right away.
+    pub fn show_notification(&mut self, noty: Notification) -> Option<u64> {
+        let repeated = self
+            .queue
+            .back()
+            .or(self.noty.as_ref())
+            .map(|last| last.msg() == noty.msg() && last.kind() == noty.kind())
+            .unwrap_or(false);
 
-        if self.noty_counter == 0 {
-            self.noty = None;
+        if self.noty.is_none() {
+            let timeout = noty.timeout();
+            self.noty = Some(noty);
+            Some(timeout)
+        } else {
+            if !repeated {
+                self.queue.push_back(noty);
+            }
+            None
         }
     }
 
+    /// Returns the timeout of the next queued notification, if any.
+    pub fn clear_notification(&mut self) -> Option<u64> {
+        self.noty = self.queue.pop_front();
+        self.noty.as_ref().map(Notification::timeout)
+    }
+
     fn map_color(noty: &Notification) -> Style {
         match noty.kind() {
             NotificationKind::Error => Style::default().fg(Color::Red),
@@ -0,0 +1,215 @@
+use arboard::Clipboard;
+use crossterm::event::{Event, KeyCode};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Wrap},
+    Frame,
+};
+
+use crate::types::info::InfoSheet;
+
+use self::msg::NotificationsMsg;
+
+use super::{
+    table::{msg::TableMsg, TableEntry, UiTable},
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
+    NotificationKind,
+};
+
+pub mod msg;
+
+const HISTORY_CAPACITY: usize = 1000;
+
+pub type NotificationsTable = UiTable<NotificationEntry, Box<NotificationsMsg>>;
+
+/// Session history of the notifications shown in the footer, newest first.
+pub struct NotificationsComponent {
+    table: NotificationsTable,
+    history: Vec<Notification>,
+    filter: Option<NotificationKind>,
+    clip: Clipboard,
+}
+
+#[derive(Debug)]
+pub struct NotificationEntry(Notification);
+
+impl TableEntry for NotificationEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.timestamp().format("%H:%M:%S").to_string(),
+            kind_label(self.0.kind()).to_string(),
+            self.0.origin().unwrap_or_default().to_string(),
+            self.0.msg().to_string(),
+        ]
+    }
+
+    fn row(&self) -> Row<'_> {
+        Row::new(vec![
+            Cell::from(self.0.timestamp().format("%H:%M:%S").to_string()),
+            Cell::from(kind_label(self.0.kind())).style(kind_style(self.0.kind())),
+            Cell::from(self.0.origin().unwrap_or_default()),
+            Cell::from(self.0.msg()),
+        ])
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["TIME", "KIND", "VIEW", "MESSAGE"]
+    }
+}
+
+impl Default for NotificationsComponent {
+    fn default() -> Self {
+        Self {
+            table: NotificationsTable::new("Notifications".to_string()),
+            history: vec![],
+            filter: None,
+            clip: Clipboard::new().unwrap(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for NotificationsComponent {
+    type Msg = NotificationsMsg;
+    type Props = ();
+
+    async fn init(&mut self, _props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        self.refresh();
+        self.table.select(0);
+        Ok(ComponentReturn::empty())
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let filter = match self.filter.as_ref() {
+            Some(kind) => kind_label(kind),
+            None => "all",
+        };
+        let title = format!(
+            " Notifications ({}/{}, filter: {}) ",
+            self.table.elements().len(),
+            self.history.len(),
+            filter
+        );
+        let block = Block::default()
+            .title_top(Line::from(Span::styled(title, Style::default().fg(Color::Blue))).centered())
+            .borders(Borders::ALL);
+        let area = block.inner(rect);
+        f.render_widget(block, rect);
+
+        let [table_area, detail_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(6)]).areas(area);
+
+        self.table.view(f, table_area);
+
+        let detail = self
+            .table
+            .selected()
+            .map(|entry| entry.0.msg())
+            .unwrap_or_default();
+        let p = Paragraph::new(detail)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP));
+        f.render_widget(p, detail_area);
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            NotificationsMsg::TableEvent(table) => {
+                Self::forward_update(&mut self.table, table.into(), NotificationsMsg::TableEvent)
+                    .await
+            }
+            NotificationsMsg::NextFilter => {
+                self.filter = match self.filter {
+                    None => Some(NotificationKind::Error),
+                    Some(NotificationKind::Error) => Some(NotificationKind::Info),
+                    Some(NotificationKind::Info) => None,
+                };
+                self.refresh();
+                self.table.select(0);
+                Ok(ComponentReturn::empty())
+            }
+            NotificationsMsg::Copy => match self.table.selected() {
+                Some(entry) => {
+                    self.clip.set_text(entry.0.msg())?;
+                    Ok(ComponentReturn::action(Action::Notification(
+                        Notification::info("Message copied!".to_string()),
+                    )))
+                }
+                None => Ok(ComponentReturn::empty()),
+            },
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if let ComponentEvent::Event(Event::Key(key)) = &evt {
+            match key.code {
+                KeyCode::Char('f') => return Ok(vec![NotificationsMsg::NextFilter.into()]),
+                KeyCode::Char('y') => return Ok(vec![NotificationsMsg::Copy.into()]),
+                _ => {}
+            }
+        }
+
+        Self::forward_event(&mut self.table, evt, |msg| match msg {
+            TableMsg::Local(table) => NotificationsMsg::TableEvent(TableMsg::Local(table)),
+            TableMsg::Outer(outer) => *outer,
+        })
+    }
+}
+
+impl NotificationsComponent {
+    pub fn info_sheet(&self) -> InfoSheet {
+        self.table
+            .info_sheet()
+            .key_binding("<f>", "Filter by kind")
+            .key_binding("<y>", "Copy message")
+    }
+
+    pub fn record(&mut self, noty: Notification) {
+        if self.history.len() == HISTORY_CAPACITY {
+            self.history.remove(0);
+        }
+        self.history.push(noty);
+        self.refresh();
+    }
+
+    fn refresh(&mut self) {
+        let selected = self.table.selected().map(|entry| *entry.0.timestamp());
+        let entries = self
+            .history
+            .iter()
+            .rev()
+            .filter(|noty| self.filter.as_ref().is_none_or(|kind| noty.kind() == kind))
+            .cloned()
+            .map(NotificationEntry)
+            .collect::<Vec<_>>();
+
+        // Keep the selection on the same message while new ones come in on top.
+        let position = selected
+            .and_then(|ts| entries.iter().position(|entry| *entry.0.timestamp() == ts))
+            .unwrap_or(0);
+        self.table.update_elements(entries);
+        self.table.select(position);
+    }
+}
+
+fn kind_label(kind: &NotificationKind) -> &'static str {
+    match kind {
+        NotificationKind::Error => "error",
+        NotificationKind::Info => "info",
+    }
+}
+
+fn kind_style(kind: &NotificationKind) -> Style {
+    match kind {
+        NotificationKind::Error => Style::default().fg(Color::Red),
+        NotificationKind::Info => Style::default().fg(Color::Cyan),
+    }
+}
@@ -0,0 +1,8 @@
+use crate::components::table::msg::TableMsg;
+
+#[derive(Debug)]
+pub enum NotificationsMsg {
+    TableEvent(TableMsg<Box<NotificationsMsg>>),
+    NextFilter,
+    Copy,
+}
@@ -0,0 +1,451 @@
+use std::{collections::HashMap, future::Future, sync::Arc};
+
+use crossterm::event::{Event, KeyCode};
+use futures::{future::BoxFuture, FutureExt};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    types::{
+        api_error::ApiError,
+        connector::Connector,
+        info::InfoSheet,
+        participant::{NewParticipantContext, ParticipantContext},
+    },
+    widgets::form::{msg::FormMsg, text::TextField, FieldComponent, Form},
+};
+
+use self::msg::ParticipantContextsMsg;
+
+use super::{
+    popup_area,
+    resources::{msg::ResourcesMsg, DrawableResource, Field, ResourcesComponent},
+    table::TableEntry,
+    Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
+};
+
+pub mod msg;
+
+#[derive(Debug, Clone)]
+pub struct ParticipantContextEntry(ParticipantContext);
+
+impl ParticipantContextEntry {
+    pub fn new(ctx: ParticipantContext) -> Self {
+        Self(ctx)
+    }
+}
+
+pub type ParticipantContextResourcesMsg =
+    ResourcesMsg<ParticipantContextEntry, ParticipantContextEntry>;
+pub type ParticipantContextResources =
+    ResourcesComponent<ParticipantContextEntry, ParticipantContextEntry>;
+
+pub type OnCreate = Arc<
+    dyn Fn(&Connector, NewParticipantContext) -> BoxFuture<'static, anyhow::Result<()>>
+        + Send
+        + Sync,
+>;
+
+pub type OnDelete = Arc<
+    dyn Fn(&Connector, ParticipantContextEntry) -> BoxFuture<'static, anyhow::Result<()>>
+        + Send
+        + Sync,
+>;
+
+impl TableEntry for ParticipantContextEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.id().to_string(),
+            self.0.identity().unwrap_or_default().to_string(),
+            self.0.state().unwrap_or_default(),
+        ]
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["ID", "IDENTITY", "STATE"]
+    }
+}
+
+impl DrawableResource for ParticipantContextEntry {
+    fn id(&self) -> &str {
+        self.0.id()
+    }
+
+    fn title() -> &'static str {
+        "Participant Contexts"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::string("id", self.0.id()),
+            Field::string("identity", self.0.identity().unwrap_or_default()),
+            Field::string("state", self.0.state().unwrap_or_default()),
+        ]
+    }
+}
+
+pub struct ParticipantContextsComponent {
+    resources: ParticipantContextResources,
+    connector: Option<Connector>,
+    form: Option<Form<NewParticipantContext>>,
+    deleting: Option<ParticipantContextEntry>,
+    on_create: Option<OnCreate>,
+    on_delete: Option<OnDelete>,
+}
+
+impl ParticipantContextsComponent {
+    pub fn new(resources: ParticipantContextResources) -> Self {
+        Self {
+            resources,
+            connector: None,
+            form: None,
+            deleting: None,
+            on_create: None,
+            on_delete: None,
+        }
+    }
+
+    pub fn on_create<F, Fut>(mut self, on_create: F) -> Self
+    where
+        F: Fn(Connector, NewParticipantContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send,
+    {
+        let handler = Arc::new(on_create);
+        self.on_create = Some(Arc::new(move |conn, ctx| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, ctx).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn on_delete<F, Fut>(mut self, on_delete: F) -> Self
+    where
+        F: Fn(Connector, ParticipantContextEntry) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send,
+    {
+        let handler = Arc::new(on_delete);
+        self.on_delete = Some(Arc::new(move |conn, entry| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, entry).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn info_sheet(&self) -> InfoSheet {
+        if !self.supported() {
+            return InfoSheet::default();
+        }
+        let sheet = self.resources.info_sheet();
+        if self.resources.is_listing() {
+            sheet
+                .key_binding("<c>", "Create context")
+                .key_binding("<d>", "Delete context")
+                .key_binding("<s>", "Use context")
+                .key_binding("<u>", "Use configured context")
+        } else {
+            sheet
+        }
+    }
+
+    fn supported(&self) -> bool {
+        self.connector
+            .as_ref()
+            .is_some_and(Connector::supports_participant_contexts)
+    }
+
+    fn form() -> Form<NewParticipantContext> {
+        Form::default()
+            .field(
+                TextField::builder()
+                    .name("id".to_string())
+                    .label("Id (optional)".to_string())
+                    .selected(true)
+                    .build()
+                    .unwrap(),
+            )
+            .field(
+                TextField::builder()
+                    .name("identity".to_string())
+                    .label("Identity".to_string())
+                    .build()
+                    .unwrap(),
+            )
+            .on_confirm(Self::parse_fields)
+    }
+
+    fn parse_fields(
+        fields: HashMap<String, FieldComponent>,
+    ) -> anyhow::Result<NewParticipantContext> {
+        let id: String = fields["id"].clone().try_into()?;
+        let identity: String = fields["identity"].clone().try_into()?;
+
+        if identity.is_empty() {
+            anyhow::bail!("Identity is required");
+        }
+
+        Ok(NewParticipantContext {
+            id: Some(id).filter(|id| !id.is_empty()),
+            identity,
+        })
+    }
+
+    fn create(
+        &mut self,
+        ctx: NewParticipantContext,
+    ) -> anyhow::Result<ComponentReturn<ParticipantContextsMsg>> {
+        self.form = None;
+        match (self.connector.clone(), self.on_create.clone()) {
+            (Some(connector), Some(on_create)) => Ok(ComponentReturn::cmd(
+                async move {
+                    let identity = ctx.identity.clone();
+                    match on_create(&connector, ctx).await {
+                        Ok(()) => Ok(vec![ParticipantContextsMsg::Changed(format!(
+                            "Participant context for {} created",
+                            identity
+                        ))
+                        .into()]),
+                        Err(err) => Ok(vec![
+                            ParticipantContextsMsg::Failed(ApiError::new(&err)).into()
+                        ]),
+                    }
+                }
+                .boxed(),
+            )),
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn delete(&mut self) -> anyhow::Result<ComponentReturn<ParticipantContextsMsg>> {
+        match (
+            self.connector.clone(),
+            self.on_delete.clone(),
+            self.deleting.take(),
+        ) {
+            (Some(connector), Some(on_delete), Some(entry)) => Ok(ComponentReturn::cmd(
+                async move {
+                    let id = entry.id().to_string();
+                    match on_delete(&connector, entry).await {
+                        Ok(()) => Ok(vec![ParticipantContextsMsg::Changed(format!(
+                            "Participant context {} deleted",
+                            id
+                        ))
+                        .into()]),
+                        Err(err) => Ok(vec![
+                            ParticipantContextsMsg::Failed(ApiError::new(&err)).into()
+                        ]),
+                    }
+                }
+                .boxed(),
+            )),
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    async fn changed(
+        &mut self,
+        msg: String,
+    ) -> anyhow::Result<ComponentReturn<ParticipantContextsMsg>> {
+        let mut ret = Self::forward_update(
+            &mut self.resources,
+            ResourcesMsg::RefreshPage.into(),
+            ParticipantContextsMsg::Resources,
+        )
+        .await?;
+        ret.actions
+            .push(Action::Notification(Notification::info(msg)));
+        Ok(ret)
+    }
+
+    fn use_context(
+        &self,
+        ctx: Option<String>,
+    ) -> anyhow::Result<ComponentReturn<ParticipantContextsMsg>> {
+        Ok(ComponentReturn::action(Action::SetParticipantContext(ctx)))
+    }
+
+    fn form_msg(msg: FormMsg<NewParticipantContext>) -> ParticipantContextsMsg {
+        match msg {
+            FormMsg::Local(local) => ParticipantContextsMsg::Form(FormMsg::Local(local)),
+            FormMsg::Outer(ctx) => ParticipantContextsMsg::Create(ctx),
+        }
+    }
+
+    fn popup_block(title: &str) -> Block<'_> {
+        Block::default()
+            .title_top(
+                Line::from(Span::styled(
+                    format!(" {} ", title),
+                    Style::default().fg(Color::Red),
+                ))
+                .centered(),
+            )
+            .borders(Borders::ALL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for ParticipantContextsComponent {
+    type Msg = ParticipantContextsMsg;
+    type Props = Connector;
+
+    async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        self.connector = Some(props.clone());
+        self.form = None;
+        self.deleting = None;
+        if !props.supports_participant_contexts() {
+            return Ok(ComponentReturn::empty());
+        }
+        Self::forward_init(
+            &mut self.resources,
+            props,
+            ParticipantContextsMsg::Resources,
+        )
+        .await
+    }
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        if !self.supported() {
+            let text = Paragraph::new("Participant contexts require a V4 connector")
+                .centered()
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(text, rect);
+            return;
+        }
+
+        self.resources.view(f, rect);
+
+        if let Some(form) = self.form.as_mut() {
+            let block = Self::popup_block("New participant context");
+            let area = popup_area(f.area(), 30, 30);
+            let content = block.inner(area);
+            f.render_widget(Clear, area);
+            f.render_widget(block, area);
+            form.view(f, content);
+        }
+
+        if let Some(entry) = self.deleting.as_ref() {
+            let area = popup_area(f.area(), 30, 15);
+            let text = Paragraph::new(vec![
+                Line::from(format!("Delete participant context {}?", entry.id())),
+                Line::from(""),
+                Line::from("<y> confirm, <n/esc> cancel"),
+            ])
+            .centered()
+            .block(Self::popup_block("Delete"));
+            f.render_widget(Clear, area);
+            f.render_widget(text, area);
+        }
+    }
+
+    async fn update(
+        &mut self,
+        msg: ComponentMsg<Self::Msg>,
+    ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
+        match msg.take() {
+            ParticipantContextsMsg::Resources(msg) => {
+                Self::forward_update(
+                    &mut self.resources,
+                    msg.into(),
+                    ParticipantContextsMsg::Resources,
+                )
+                .await
+            }
+            ParticipantContextsMsg::ShowCreate => {
+                self.form = Some(Self::form());
+                Ok(ComponentReturn::empty())
+            }
+            ParticipantContextsMsg::HideCreate => {
+                self.form = None;
+                Ok(ComponentReturn::empty())
+            }
+            ParticipantContextsMsg::Form(msg) => match self.form.as_mut() {
+                Some(form) => Self::forward_update(form, msg.into(), Self::form_msg).await,
+                None => Ok(ComponentReturn::empty()),
+            },
+            ParticipantContextsMsg::Create(ctx) => self.create(ctx),
+            ParticipantContextsMsg::AskDelete => {
+                self.deleting = self.resources.selected().cloned();
+                Ok(ComponentReturn::empty())
+            }
+            ParticipantContextsMsg::CancelDelete => {
+                self.deleting = None;
+                Ok(ComponentReturn::empty())
+            }
+            ParticipantContextsMsg::ConfirmDelete => self.delete(),
+            ParticipantContextsMsg::Changed(msg) => self.changed(msg).await,
+            ParticipantContextsMsg::Failed(error) => {
+                Ok(ComponentReturn::action(Action::Error(error)))
+            }
+            ParticipantContextsMsg::Use => self.use_context(
+                self.resources
+                    .selected()
+                    .map(|entry| entry.id().to_string()),
+            ),
+            ParticipantContextsMsg::UseDefault => self.use_context(
+                self.connector
+                    .as_ref()
+                    .and_then(|c| c.config().participant_context_id().cloned()),
+            ),
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        evt: ComponentEvent,
+    ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if !self.supported() {
+            return Ok(vec![]);
+        }
+
+        if let Some(form) = self.form.as_mut() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ParticipantContextsMsg::HideCreate.into()])
+                }
+                evt => Self::forward_event(form, evt, Self::form_msg),
+            };
+        }
+
+        if self.deleting.is_some() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) => match key.code {
+                    KeyCode::Char('y') => Ok(vec![ParticipantContextsMsg::ConfirmDelete.into()]),
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        Ok(vec![ParticipantContextsMsg::CancelDelete.into()])
+                    }
+                    _ => Ok(vec![]),
+                },
+                _ => Ok(vec![]),
+            };
+        }
+
+        if self.resources.is_listing() {
+            if let ComponentEvent::Event(Event::Key(key)) = &evt {
+                match key.code {
+                    KeyCode::Char('c') if self.on_create.is_some() => {
+                        return Ok(vec![ParticipantContextsMsg::ShowCreate.into()])
+                    }
+                    KeyCode::Char('d') if self.on_delete.is_some() => {
+                        return Ok(vec![ParticipantContextsMsg::AskDelete.into()])
+                    }
+                    KeyCode::Char('s') => return Ok(vec![ParticipantContextsMsg::Use.into()]),
+                    KeyCode::Char('u') => {
+                        return Ok(vec![ParticipantContextsMsg::UseDefault.into()])
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Self::forward_event(&mut self.resources, evt, ParticipantContextsMsg::Resources)
+    }
+}
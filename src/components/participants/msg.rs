@@ -0,0 +1,22 @@
+use crate::{
+    types::{api_error::ApiError, participant::NewParticipantContext},
+    widgets::form::msg::FormMsg,
+};
+
+use super::ParticipantContextResourcesMsg;
+
+#[derive(Debug)]
+pub enum ParticipantContextsMsg {
+    Resources(ParticipantContextResourcesMsg),
+    ShowCreate,
+    HideCreate,
+    Form(FormMsg<NewParticipantContext>),
+    Create(NewParticipantContext),
+    AskDelete,
+    CancelDelete,
+    ConfirmDelete,
+    Changed(String),
+    Failed(ApiError),
+    Use,
+    UseDefault,
+}
@@ -1,5 +1,4 @@
 use edc_connector_client::types::policy::PolicyDefinition;
-use ratatui::widgets::Row;
 
 use super::{
     resources::{msg::ResourcesMsg, DrawableResource, Field, FieldValue, ResourcesComponent},
@@ -20,13 +19,13 @@ impl PolicyDefinitionEntry {
 }
 
 impl TableEntry for PolicyDefinitionEntry {
-    fn row(&self) -> ratatui::widgets::Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let policy = serde_json::to_string(self.0.policy()).unwrap();
-        Row::new(vec![self.0.id().to_string(), policy])
+        vec![self.0.id().to_string(), policy]
     }
 
-    fn headers() -> ratatui::widgets::Row<'static> {
-        Row::new(vec!["ID", "POLICY"])
+    fn columns() -> Vec<&'static str> {
+        vec!["ID", "POLICY"]
     }
 }
 
@@ -0,0 +1,95 @@
+use crate::client::log::RecordedRequest;
+
+use super::{
+    resources::{msg::ResourcesMsg, DrawableResource, Field, FieldValue, ResourcesComponent},
+    table::TableEntry,
+};
+
+#[derive(Debug, Clone)]
+pub struct RequestEntry(RecordedRequest);
+
+impl RequestEntry {
+    pub fn new(request: RecordedRequest) -> Self {
+        Self(request)
+    }
+
+    fn status(&self) -> String {
+        match (self.0.status, self.0.error.as_ref()) {
+            (Some(status), _) => status.to_string(),
+            (None, Some(_)) => String::from("failed"),
+            (None, None) => String::from("n/a"),
+        }
+    }
+
+    fn latency(&self) -> String {
+        format!("{}ms", self.0.latency.as_millis())
+    }
+}
+
+pub type RequestsMsg = ResourcesMsg<RequestEntry, RequestEntry>;
+pub type RequestsComponent = ResourcesComponent<RequestEntry, RequestEntry>;
+
+impl TableEntry for RequestEntry {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.0.timestamp.format("%H:%M:%S%.3f").to_string(),
+            self.0.method.clone(),
+            self.0.url.clone(),
+            self.status(),
+            self.latency(),
+        ]
+    }
+
+    fn columns() -> Vec<&'static str> {
+        vec!["TIME", "METHOD", "URL", "STATUS", "LATENCY"]
+    }
+}
+
+impl DrawableResource for RequestEntry {
+    fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    fn title() -> &'static str {
+        "Requests"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        let headers = self
+            .0
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut fields = vec![
+            Field::string("method", &self.0.method),
+            Field::string("url", &self.0.url),
+            Field::string("status", self.status()),
+            Field::string("latency", self.latency()),
+            Field::string("timestamp", self.0.timestamp.to_rfc3339()),
+        ];
+        if let Some(error) = &self.0.error {
+            fields.push(Field::string("error", error));
+        }
+        fields.push(Field::new("headers".to_string(), FieldValue::Json(headers)));
+        if let Some(body) = &self.0.body {
+            fields.push(Field::new(
+                "body".to_string(),
+                FieldValue::Json(body.clone()),
+            ));
+        }
+        if let Some(response) = &self.0.response {
+            fields.push(Field::new(
+                "response".to_string(),
+                FieldValue::Json(response.clone()),
+            ));
+        }
+        fields.push(Field::new(
+            "curl".to_string(),
+            FieldValue::Json(self.0.to_curl()),
+        ));
+        fields
+    }
+}
@@ -1,27 +1,52 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
 
-use self::{msg::ResourcesMsg, resource::ResourceComponent};
+use self::{
+    diff::{DiffSide, ResourceDiff},
+    export::ExportScope,
+    msg::ResourcesMsg,
+    paging::Total,
+    resource::ResourceComponent,
+};
 use super::{
+    popup_area,
     table::{msg::TableMsg, TableEntry, UiTable},
     Action, Component, ComponentEvent, ComponentMsg, ComponentReturn, Notification,
 };
-use crate::types::{connector::Connector, info::InfoSheet};
+use crate::{
+    app::fetch::{fetch_pages, Pager},
+    types::{
+        api_error::ApiError,
+        connector::{is_unauthorized, Connector},
+        copy::CopyRequest,
+        history::StateHistory,
+        info::InfoSheet,
+        manifest::{manifest_path, ManifestKind},
+        query::StateCodes,
+    },
+    widgets::form::{msg::FormMsg, text::TextField, FieldComponent, Form},
+};
 use crossterm::event::{Event, KeyCode};
 use edc_connector_client::types::query::Query;
 use filter::{Filter, FilterMsg};
+use follow::{FollowState, Follower, FOLLOW_INTERVAL};
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 use serde::Serialize;
+use serde_json::Value;
 use std::future::Future;
+pub mod diff;
+pub mod export;
 pub mod filter;
+pub mod follow;
 pub mod msg;
+pub mod paging;
 pub mod resource;
 
 pub type ResourceTable<T, R> = UiTable<T, Box<ResourcesMsg<T, R>>>;
@@ -32,10 +57,14 @@ pub type OnFetch<T> =
 pub type OnSingleFetch<T, R> =
     Arc<dyn Fn(&Connector, T) -> BoxFuture<'static, anyhow::Result<R>> + Send + Sync>;
 
+pub type OnFollow<T> =
+    Arc<dyn Fn(&Connector, T) -> BoxFuture<'static, anyhow::Result<FollowState>> + Send + Sync>;
+
 #[derive(Debug)]
 pub enum Focus {
     ResourceList,
     Resource,
+    Diff,
 }
 
 pub struct ResourcesComponent<T: TableEntry, R: DrawableResource> {
@@ -45,13 +74,31 @@ pub struct ResourcesComponent<T: TableEntry, R: DrawableResource> {
     query: Query,
     focus: Focus,
     show_filters: bool,
+    follower: Follower,
+    following: Option<T>,
+    history: StateHistory,
+    copy_kind: Option<ManifestKind>,
+    marked: Option<DiffSide>,
+    diff: Option<ResourceDiff>,
+    export: Option<(ExportScope, Form<String>)>,
+    export_path: Option<String>,
+    total: Option<Total>,
+    page_form: Option<Form<u32>>,
+    /// The session of the running load of all pages.
+    loading: Option<u64>,
+    pager: Option<Pager>,
+    load_session: u64,
+    all_loaded: bool,
     connector: Option<Connector>,
     on_fetch: Option<OnFetch<T>>,
     on_single_fetch: Option<OnSingleFetch<T, R>>,
+    on_follow: Option<OnFollow<T>>,
 }
 
-impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync + 'static>
-    ResourcesComponent<T, R>
+impl<
+        T: TableEntry + DrawableResource + Clone + Send + Sync + 'static,
+        R: DrawableResource + Send + Sync + 'static,
+    > ResourcesComponent<T, R>
 {
     pub fn on_fetch<F, Fut>(mut self, on_fetch: F) -> Self
     where
@@ -83,10 +130,96 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
         self
     }
 
+    pub fn on_follow<F, Fut>(mut self, on_follow: F) -> Self
+    where
+        F: Fn(Connector, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<FollowState>> + Send,
+    {
+        let handler = Arc::new(on_follow);
+        self.on_follow = Some(Arc::new(move |conn, entity| {
+            let c = conn.clone();
+            let inner_handler = handler.clone();
+            async move { inner_handler(c, entity).await }.boxed()
+        }));
+
+        self
+    }
+
+    pub fn with_history(mut self, history: StateHistory) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Allows copying the selected resource to another connector.
+    pub fn with_copy(mut self, kind: ManifestKind) -> Self {
+        self.copy_kind = Some(kind);
+        self
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.table.selected()
+    }
+
+    /// Whether the list is shown without any popup on top, so keys can be handled by a wrapper.
+    pub fn is_listing(&self) -> bool {
+        matches!(self.focus, Focus::ResourceList)
+            && !self.show_filters
+            && self.following.is_none()
+            && self.export.is_none()
+            && self.page_form.is_none()
+    }
+
+    pub fn set_query(&mut self, query: Query) {
+        self.stop_loading();
+        self.query = query;
+        self.total = None;
+        self.focus = Focus::ResourceList;
+    }
+
+    fn history_key(&self, id: &str) -> String {
+        let connector = self
+            .connector
+            .as_ref()
+            .map(|c| c.config().name())
+            .unwrap_or_default();
+        format!("{}/{}", connector, id)
+    }
+
+    /// Records the observed states, persisting the history once for the whole batch.
+    fn record_states<'a>(
+        &mut self,
+        states: impl IntoIterator<Item = (&'a str, Option<String>, Option<i64>)>,
+    ) {
+        let mut changed = false;
+        for (id, state, state_timestamp) in states {
+            if let Some(state) = state {
+                let key = self.history_key(id);
+                changed |= self.history.record(&key, &state, state_timestamp);
+            }
+        }
+        if changed {
+            if let Err(err) = self.history.save() {
+                tracing::warn!("Failed to persist state history: {}", err);
+            }
+        }
+    }
+
     pub fn info_sheet(&self) -> InfoSheet {
         match self.focus {
-            Focus::ResourceList => self.table.info_sheet().merge(self.pagination_sheet()),
+            Focus::ResourceList => self
+                .table
+                .info_sheet()
+                .merge(self.pagination_sheet())
+                .merge(self.follow_sheet())
+                .merge(self.copy_sheet())
+                .merge(self.diff_sheet())
+                .key_binding("<e>", ExportScope::Page.label())
+                .key_binding("<E>", ExportScope::All.label()),
             Focus::Resource => self.resource.info_sheet(),
+            Focus::Diff => InfoSheet::default()
+                .key_binding("<j/down>", "Down")
+                .key_binding("<k/up>", "Up")
+                .key_binding("<esc>", "Back"),
         }
     }
 
@@ -94,10 +227,415 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
         InfoSheet::default()
             .key_binding("<n>", "Next Page")
             .key_binding("<p>", "Prev page")
+            .key_binding("<0>", "First page")
+            .key_binding("<$>", "Last page")
+            .key_binding("<g>", "Go to page")
+            .key_binding("<a>", "Load all pages")
             .key_binding("<r>", "Refresh page")
             .key_binding("<f>", "Filters")
     }
 
+    fn stop_loading(&mut self) {
+        self.load_session += 1;
+        self.loading = None;
+        self.pager = None;
+        self.all_loaded = false;
+    }
+
+    fn go_to(&mut self, offset: u32) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        self.stop_loading();
+        self.query = self.query.to_builder().offset(offset).build();
+        self.fetch()
+    }
+
+    fn next_page(&mut self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        let offset = self.query.offset() + self.query.limit();
+        let has_next = match self.total {
+            Some(Total::Exact(total)) => offset < total,
+            _ => self.table.elements().len() as u32 >= self.query.limit(),
+        };
+        if has_next {
+            self.go_to(offset)
+        } else {
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn last_page(&mut self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        let limit = self.query.limit();
+        if let Some(offset) = self.total.and_then(|total| total.last_offset(limit)) {
+            return self.go_to(offset);
+        }
+
+        // Without an exact total, pages are walked until the last one, as fetchers of unpaged
+        // APIs may ignore the offset.
+        self.stop_loading();
+        if let (Some(connector), Some(on_fetch)) = (self.connector.clone(), self.on_fetch.clone()) {
+            let query = self.query.clone();
+            Ok(ComponentReturn::cmd(
+                async move {
+                    let mut pager = Pager::new(query.clone());
+                    let mut last = (query.offset(), vec![]);
+                    while let Some(page_query) = pager.next_query() {
+                        let offset = page_query.offset();
+                        let page = match on_fetch(&connector, page_query).await {
+                            Ok(page) => page,
+                            Err(err) => return Ok(Self::fetch_failed(err)),
+                        };
+                        let new = pager.add(page.clone(), T::id);
+                        if !new.is_empty() || offset == query.offset() {
+                            last = (offset, page);
+                        }
+                    }
+                    Ok(vec![ResourcesMsg::LastPageFetched(last.0, last.1).into()])
+                }
+                .boxed(),
+            ))
+        } else {
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn page_form(&self) -> Form<u32> {
+        Form::default()
+            .field(
+                TextField::builder()
+                    .name("page".to_string())
+                    .label("Page".to_string())
+                    .initial_value(self.page().to_string())
+                    .selected(true)
+                    .build()
+                    .unwrap(),
+            )
+            .on_confirm(Self::parse_page_fields)
+    }
+
+    fn parse_page_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<u32> {
+        let page: String = fields["page"].clone().try_into()?;
+        match page.trim().parse::<u32>() {
+            Ok(page) if page > 0 => Ok(page),
+            _ => anyhow::bail!("Page must be a number greater than 0"),
+        }
+    }
+
+    fn page_form_msg(msg: FormMsg<u32>) -> ResourcesMsg<T, R> {
+        match msg {
+            FormMsg::Local(local) => ResourcesMsg::PageForm(FormMsg::Local(local)),
+            FormMsg::Outer(page) => ResourcesMsg::GoToPage(page),
+        }
+    }
+
+    fn page(&self) -> u32 {
+        match self.query.limit() {
+            0 => 1,
+            limit => self.query.offset() / limit + 1,
+        }
+    }
+
+    fn load_all(&mut self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        self.stop_loading();
+        self.loading = Some(self.load_session);
+        self.query = self.query.to_builder().offset(0).build();
+        self.table.update_elements(vec![]);
+        let pager = Pager::new(self.query.clone());
+        let query = pager.next_query();
+        self.pager = Some(pager);
+        match query {
+            Some(query) => self.load_page(self.load_session, query),
+            None => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn load_page(
+        &self,
+        session: u64,
+        query: Query,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if let (Some(connector), Some(on_fetch)) = (self.connector.clone(), self.on_fetch.clone()) {
+            Ok(ComponentReturn::cmd(
+                async move {
+                    match on_fetch(&connector, query).await {
+                        Ok(page) => Ok(vec![ResourcesMsg::AllPageFetched(session, page).into()]),
+                        Err(err) => {
+                            let mut msgs = Self::fetch_failed(err);
+                            msgs.push(ResourcesMsg::LoadAllFailed(session).into());
+                            Ok(msgs)
+                        }
+                    }
+                }
+                .boxed(),
+            ))
+        } else {
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn all_page_fetched(
+        &mut self,
+        session: u64,
+        page: Vec<T>,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if self.loading != Some(session) {
+            return Ok(ComponentReturn::empty());
+        }
+        self.record_states(
+            page.iter()
+                .map(|res| (res.id(), res.state(), res.state_timestamp())),
+        );
+        let Some(pager) = self.pager.as_mut() else {
+            return Ok(ComponentReturn::empty());
+        };
+        let mut elements = self.table.elements().to_vec();
+        elements.extend(pager.add(page, T::id));
+        let next = pager.next_query();
+        let loaded = elements.len() as u32;
+        self.table.update_elements(elements);
+
+        match next {
+            Some(query) => {
+                self.total = Some(Total::AtLeast(loaded));
+                self.load_page(session, query)
+            }
+            None => {
+                self.loading = None;
+                self.pager = None;
+                self.all_loaded = true;
+                self.total = Some(Total::Exact(loaded));
+                Ok(ComponentReturn::empty())
+            }
+        }
+    }
+
+    fn follow_sheet(&self) -> InfoSheet {
+        if self.on_follow.is_some() {
+            InfoSheet::default().key_binding("<w>", "Follow state")
+        } else {
+            InfoSheet::default()
+        }
+    }
+
+    fn copy_sheet(&self) -> InfoSheet {
+        if self.copy_kind.is_some() {
+            InfoSheet::default().key_binding("<c>", "Copy to connector")
+        } else {
+            InfoSheet::default()
+        }
+    }
+
+    fn diff_sheet(&self) -> InfoSheet {
+        if self.on_single_fetch.is_some() {
+            InfoSheet::default()
+                .key_binding("<m>", "Mark for diff")
+                .key_binding("<=>", "Diff with marked")
+        } else {
+            InfoSheet::default()
+        }
+    }
+
+    fn diff_side(&self, resource: &R) -> DiffSide {
+        let connector = self
+            .connector
+            .as_ref()
+            .map(|c| c.config().name())
+            .unwrap_or_default();
+        DiffSide::new(connector, resource.id(), resource.to_json())
+    }
+
+    fn diff(&self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        match (self.marked.as_ref(), self.table.selected().cloned()) {
+            (Some(_), Some(selected)) => self.fetch_one(selected, ResourcesMsg::DiffFetched),
+            (None, _) => Ok(ComponentReturn::action(Action::Notification(
+                Notification::info(String::from("Mark a resource with <m> to diff against")),
+            ))),
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn export_form(&self) -> Form<String> {
+        let initial = self
+            .export_path
+            .clone()
+            .unwrap_or_else(|| format!("{}.csv", R::title().to_lowercase().replace(' ', "-")));
+        Form::default()
+            .field(
+                TextField::builder()
+                    .name("path".to_string())
+                    .label("File (.csv, .json or .ndjson)".to_string())
+                    .initial_value(initial)
+                    .selected(true)
+                    .build()
+                    .unwrap(),
+            )
+            .on_confirm(Self::parse_export_fields)
+    }
+
+    fn parse_export_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<String> {
+        let path: String = fields["path"].clone().try_into()?;
+        let path = path.trim().to_string();
+        if path.is_empty() {
+            anyhow::bail!("Path is required");
+        }
+        export::ExportFormat::from_path(&manifest_path(&path))?;
+        Ok(path)
+    }
+
+    fn export_form_msg(msg: FormMsg<String>) -> ResourcesMsg<T, R> {
+        match msg {
+            FormMsg::Local(local) => ResourcesMsg::ExportForm(FormMsg::Local(local)),
+            FormMsg::Outer(path) => ResourcesMsg::Export(path),
+        }
+    }
+
+    fn export(&mut self, path: String) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        let Some((scope, _)) = self.export.take() else {
+            return Ok(ComponentReturn::empty());
+        };
+        self.export_path = Some(path.clone());
+
+        let page = match scope {
+            ExportScope::Page => Some(self.table.elements().to_vec()),
+            ExportScope::All => None,
+        };
+        let fetch = self.connector.clone().zip(self.on_fetch.clone());
+        let query = self.query.clone();
+        Ok(ComponentReturn::cmd(
+            async move {
+                let elements = match (page, fetch) {
+                    (Some(page), _) => Ok(page),
+                    (None, Some((connector, on_fetch))) => {
+                        fetch_pages(query.to_builder().offset(0).build(), T::id, |q| {
+                            on_fetch(&connector, q)
+                        })
+                        .await
+                    }
+                    (None, None) => Ok(vec![]),
+                };
+                let written = elements.and_then(|elements| {
+                    export::write(&manifest_path(&path), &elements)?;
+                    Ok(elements.len())
+                });
+                match written {
+                    Ok(count) => Ok(vec![ResourcesMsg::Exported(path, count).into()]),
+                    Err(err) => Ok(vec![ResourcesMsg::ExportFailed(format!(
+                        "Failed to export to {}: {}",
+                        path, err
+                    ))
+                    .into()]),
+                }
+            }
+            .boxed(),
+        ))
+    }
+
+    fn copy(&self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        match (
+            self.connector.clone(),
+            self.copy_kind,
+            self.table.selected(),
+        ) {
+            (Some(source), Some(kind), Some(selected)) => {
+                Ok(ComponentReturn::action(Action::Copy(CopyRequest {
+                    source,
+                    kind,
+                    id: selected.id().to_string(),
+                })))
+            }
+            _ => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn start_follow(&mut self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if self.on_follow.is_none() {
+            return Ok(ComponentReturn::empty());
+        }
+        match self.table.selected().cloned() {
+            Some(selected) => {
+                let session = self.follower.start();
+                self.following = Some(selected);
+                self.follow_fetch(session, Duration::ZERO)
+            }
+            None => Ok(ComponentReturn::empty()),
+        }
+    }
+
+    fn follow_fetch(
+        &self,
+        session: u64,
+        delay: Duration,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if let (Some(connector), Some(on_follow), Some(following)) = (
+            self.connector.as_ref(),
+            self.on_follow.as_ref(),
+            self.following.clone(),
+        ) {
+            let connector = connector.clone();
+            let on_follow = on_follow.clone();
+            Ok(ComponentReturn::cmd(
+                async move {
+                    tokio::time::sleep(delay).await;
+                    match on_follow(&connector, following).await {
+                        Ok(state) => {
+                            Ok(vec![ResourcesMsg::FollowStateFetched(session, state).into()])
+                        }
+                        Err(err) => Ok(vec![
+                            ResourcesMsg::FollowFailed(session, err.to_string()).into()
+                        ]),
+                    }
+                }
+                .boxed(),
+            ))
+        } else {
+            Ok(ComponentReturn::empty())
+        }
+    }
+
+    fn follow_state_fetched(
+        &mut self,
+        session: u64,
+        state: FollowState,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if !self.follower.is_current(session) {
+            return Ok(ComponentReturn::empty());
+        }
+
+        self.follower.observe(&state);
+        self.record_states([(
+            state.id(),
+            Some(state.state().to_string()),
+            state.state_timestamp(),
+        )]);
+
+        if state.is_terminal() {
+            Ok(ComponentReturn::action(Action::Notification(
+                Notification::info(format!("{} reached state {}", state.id(), state.state())),
+            )))
+        } else if self.follower.is_timed_out() {
+            Ok(ComponentReturn::action(Action::Notification(
+                Notification::error(format!(
+                    "Timed out while following {}, last state {}",
+                    state.id(),
+                    state.state()
+                )),
+            )))
+        } else {
+            self.follow_fetch(session, FOLLOW_INTERVAL)
+        }
+    }
+
+    fn follow_failed(
+        &mut self,
+        session: u64,
+        error: String,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        if !self.follower.is_current(session) {
+            return Ok(ComponentReturn::empty());
+        }
+        self.follower.fail(error.clone());
+        Ok(ComponentReturn::action(Action::Notification(
+            Notification::error(error),
+        )))
+    }
+
     fn fetch(&self) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
         if let (Some(connector), Some(on_fetch)) = (self.connector.as_ref(), self.on_fetch.as_ref())
         {
@@ -109,9 +647,7 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
                 async move {
                     match on_fetch(&connector, query).await {
                         Ok(elements) => Ok(vec![ResourcesMsg::ResourcesFetched(elements).into()]),
-                        Err(err) => Ok(vec![
-                            ResourcesMsg::ResourcesFetchFailed(err.to_string()).into()
-                        ]),
+                        Err(err) => Ok(Self::fetch_failed(err)),
                     }
                 }
                 .boxed(),
@@ -121,7 +657,38 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
         }
     }
 
+    fn page_fetched(&mut self, resources: Vec<T>) {
+        self.record_states(
+            resources
+                .iter()
+                .map(|res| (res.id(), res.state(), res.state_timestamp())),
+        );
+        self.total = Total::observe(
+            self.total,
+            self.query.offset(),
+            resources.len() as u32,
+            self.query.limit(),
+        );
+        self.table.update_elements(resources);
+    }
+
+    fn fetch_failed(err: anyhow::Error) -> Vec<ComponentMsg<ResourcesMsg<T, R>>> {
+        let mut msgs = vec![ResourcesMsg::ResourcesFetchFailed(ApiError::new(&err)).into()];
+        if is_unauthorized(&err) {
+            msgs.push(ResourcesMsg::Unauthorized.into());
+        }
+        msgs
+    }
+
     fn single_fetch(&self, selected: T) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
+        self.fetch_one(selected, ResourcesMsg::ResourceFetched)
+    }
+
+    fn fetch_one(
+        &self,
+        selected: T,
+        fetched: fn(R) -> ResourcesMsg<T, R>,
+    ) -> anyhow::Result<ComponentReturn<ResourcesMsg<T, R>>> {
         if let (Some(connector), Some(on_single_fetch)) =
             (self.connector.as_ref(), self.on_single_fetch.as_ref())
         {
@@ -130,10 +697,8 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
             Ok(ComponentReturn::cmd(
                 async move {
                     match on_single_fetch(&connector, selected).await {
-                        Ok(element) => Ok(vec![ResourcesMsg::ResourceFetched(element).into()]),
-                        Err(err) => Ok(vec![
-                            ResourcesMsg::ResourcesFetchFailed(err.to_string()).into()
-                        ]),
+                        Ok(element) => Ok(vec![fetched(element).into()]),
+                        Err(err) => Ok(Self::fetch_failed(err)),
                     }
                 }
                 .boxed(),
@@ -167,27 +732,39 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
             .sort()
             .map(|s| format!("{}[{:?}]", s.field(), s.order()))
             .unwrap_or_else(|| String::from("None"));
-        let filter = self
-            .query
-            .filter_expression()
-            .iter()
-            .map(|criterion| {
-                format!(
-                    "{} {} {:?}",
-                    criterion.operand_left(),
-                    criterion.operator(),
-                    criterion.operand_right()
-                )
-            })
-            .collect::<Vec<_>>();
-
-        let text = format!(
-            "Offset: {} | Limit: {} | Sort: {} | Filter: [{}]",
+        let filter = filter::filter_expression(&self.query, R::state_codes());
+
+        let total = self
+            .total
+            .map(|total| total.to_string())
+            .unwrap_or_else(|| String::from("?"));
+        let page = match (self.loading, self.all_loaded) {
+            (Some(_), _) => format!(
+                "Loading all: {} <esc> to cancel",
+                self.table.elements().len()
+            ),
+            (None, true) => format!("Page: all | Total: {}", total),
+            (None, false) => format!(
+                "Page: {}/{} | Total: {}",
+                self.page(),
+                self.total
+                    .map(|total| total.pages(self.query.limit()))
+                    .unwrap_or_else(|| String::from("?")),
+                total
+            ),
+        };
+
+        let mut text = format!(
+            "{} | Offset: {} | Limit: {} | Sort: {} | Filter: [{}]",
+            page,
             self.query.offset(),
             self.query.limit(),
             sort,
-            filter.join(" , ")
+            filter
         );
+        if let Some(marked) = self.marked.as_ref() {
+            text.push_str(&format!(" | Marked: {}", marked.label()));
+        }
         let info_footer = Paragraph::new(Line::from(text))
             .centered()
             .block(Block::default().borders(Borders::TOP));
@@ -205,24 +782,44 @@ impl<T: TableEntry + Clone, R: DrawableResource> Default for ResourcesComponent<
             focus: Focus::ResourceList,
             connector: None,
             show_filters: false,
+            follower: Follower::default(),
+            following: None,
+            history: StateHistory::default(),
+            copy_kind: None,
+            marked: None,
+            diff: None,
+            export: None,
+            export_path: None,
+            total: None,
+            page_form: None,
+            loading: None,
+            pager: None,
+            load_session: 0,
+            all_loaded: false,
             on_fetch: None,
+            on_follow: None,
             query: Query::default(),
             on_single_fetch: None,
             filter: Filter::new(Query::default())
+                .with_state_codes(R::state_codes())
                 .on_confirm(|query| Box::new(ResourcesMsg::ChangeQuery(query))),
         }
     }
 }
 
 #[async_trait::async_trait]
-impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync + 'static> Component
-    for ResourcesComponent<T, R>
+impl<
+        T: TableEntry + DrawableResource + Clone + Send + Sync + 'static,
+        R: DrawableResource + Send + Sync + 'static,
+    > Component for ResourcesComponent<T, R>
 {
     type Msg = ResourcesMsg<T, R>;
     type Props = Connector;
 
     async fn init(&mut self, props: Self::Props) -> anyhow::Result<ComponentReturn<Self::Msg>> {
         self.connector = Some(props.clone());
+        self.stop_loading();
+        self.total = None;
         self.fetch()
     }
 
@@ -230,11 +827,28 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
         match self.focus {
             Focus::ResourceList => self.view_table(f, rect),
             Focus::Resource => self.resource.view(f, rect),
+            Focus::Diff => {
+                if let Some(diff) = self.diff.as_mut() {
+                    diff.view(f, rect)
+                }
+            }
         };
 
         if self.show_filters {
             self.filter.view(f, rect);
         }
+
+        if self.following.is_some() {
+            self.follower.view(f, rect);
+        }
+
+        if let Some((scope, form)) = self.export.as_mut() {
+            view_form_popup(f, scope.label(), form);
+        }
+
+        if let Some(form) = self.page_form.as_mut() {
+            view_form_popup(f, "Go to page", form);
+        }
     }
 
     async fn update(
@@ -243,6 +857,10 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
     ) -> anyhow::Result<ComponentReturn<Self::Msg>> {
         match msg.take() {
             ResourcesMsg::ResourceFetched(resource) => {
+                self.record_states([(resource.id(), resource.state(), resource.state_timestamp())]);
+                let key = self.history_key(resource.id());
+                self.resource
+                    .update_history(self.history.get(&key).to_vec());
                 self.resource.update_resource(Some(resource));
                 self.focus = Focus::Resource;
                 Ok(ComponentReturn::action(Action::ChangeSheet))
@@ -259,7 +877,7 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
                 .await
             }
             ResourcesMsg::ResourcesFetched(resources) => {
-                self.table.update_elements(resources);
+                self.page_fetched(resources);
                 Ok(ComponentReturn::empty())
             }
             ResourcesMsg::ShowFilters => {
@@ -273,27 +891,76 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
             }
             ResourcesMsg::Back => {
                 self.focus = Focus::ResourceList;
+                self.diff = None;
                 Ok(ComponentReturn::action(Action::ChangeSheet))
             }
-            ResourcesMsg::NextPage => {
-                if self.table.elements().len() as u32 == self.query.limit() {
-                    self.filter.next_page();
-                    self.fetch()
+            ResourcesMsg::NextPage => self.next_page(),
+            ResourcesMsg::PrevPage => {
+                if self.query.offset() > 0 {
+                    self.go_to(self.query.offset().saturating_sub(self.query.limit()))
                 } else {
                     Ok(ComponentReturn::empty())
                 }
             }
-            ResourcesMsg::PrevPage => {
-                if self.query.offset() > 0 {
-                    self.filter.prev_page();
-                    self.fetch()
+            ResourcesMsg::FirstPage => self.go_to(0),
+            ResourcesMsg::LastPage => self.last_page(),
+            ResourcesMsg::LastPageFetched(offset, page) => {
+                self.query = self.query.to_builder().offset(offset).build();
+                self.page_fetched(page);
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::ShowPageForm => {
+                self.page_form = Some(self.page_form());
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::HidePageForm => {
+                self.page_form = None;
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::PageForm(msg) => match self.page_form.as_mut() {
+                Some(form) => Self::forward_update(form, msg.into(), Self::page_form_msg).await,
+                None => Ok(ComponentReturn::empty()),
+            },
+            ResourcesMsg::GoToPage(page) => {
+                self.page_form = None;
+                let limit = self.query.limit();
+                let last = self.total.and_then(|total| total.last_offset(limit));
+                match (page.saturating_sub(1).checked_mul(limit), last) {
+                    (Some(offset), Some(last)) => self.go_to(offset.min(last)),
+                    (Some(offset), None) => self.go_to(offset),
+                    (None, _) => self.last_page(),
+                }
+            }
+            ResourcesMsg::LoadAll => self.load_all(),
+            ResourcesMsg::AllPageFetched(session, page) => self.all_page_fetched(session, page),
+            ResourcesMsg::LoadAllFailed(session) => {
+                if self.loading == Some(session) {
+                    self.loading = None;
+                    self.pager = None;
+                }
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::CancelLoadAll => {
+                self.stop_loading();
+                Ok(ComponentReturn::action(Action::Notification(
+                    Notification::info(format!(
+                        "Stopped loading after {} {}",
+                        self.table.elements().len(),
+                        R::title().to_lowercase()
+                    )),
+                )))
+            }
+            ResourcesMsg::RefreshPage => {
+                if self.all_loaded {
+                    self.load_all()
                 } else {
-                    Ok(ComponentReturn::empty())
+                    self.fetch()
                 }
             }
-            ResourcesMsg::RefreshPage => self.fetch(),
             ResourcesMsg::ChangeQuery(query) => {
                 self.show_filters = false;
+                self.stop_loading();
+                self.total = None;
                 self.query = query;
                 self.fetch()
             }
@@ -301,9 +968,84 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
                 Self::forward_update(&mut self.resource, msg.into(), ResourcesMsg::ResourceMsg)
                     .await
             }
-            ResourcesMsg::ResourcesFetchFailed(error) => Ok(ComponentReturn::action(
-                Action::Notification(Notification::error(error)),
+            ResourcesMsg::Unauthorized => match self.connector.clone() {
+                Some(connector) => Ok(ComponentReturn::action(Action::RequestCredentials(
+                    connector,
+                ))),
+                None => Ok(ComponentReturn::empty()),
+            },
+            ResourcesMsg::ResourcesFetchFailed(error) => {
+                Ok(ComponentReturn::action(Action::Error(error)))
+            }
+            ResourcesMsg::StartFollow => self.start_follow(),
+            ResourcesMsg::StopFollow => {
+                self.follower.stop();
+                self.following = None;
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::FollowStateFetched(session, state) => {
+                self.follow_state_fetched(session, state)
+            }
+            ResourcesMsg::FollowFailed(session, error) => self.follow_failed(session, error),
+            ResourcesMsg::Copy => self.copy(),
+            ResourcesMsg::Mark => match self.table.selected().cloned() {
+                Some(selected) => self.fetch_one(selected, ResourcesMsg::Marked),
+                None => Ok(ComponentReturn::empty()),
+            },
+            ResourcesMsg::Marked(resource) => {
+                let marked = self.diff_side(&resource);
+                let msg = format!("Marked {} for diff", marked.label());
+                self.marked = Some(marked);
+                Ok(ComponentReturn::action(Action::Notification(
+                    Notification::info(msg),
+                )))
+            }
+            ResourcesMsg::Diff => self.diff(),
+            ResourcesMsg::DiffFetched(resource) => {
+                if let Some(marked) = self.marked.clone() {
+                    self.diff = Some(ResourceDiff::new(marked, self.diff_side(&resource)));
+                    self.focus = Focus::Diff;
+                }
+                Ok(ComponentReturn::action(Action::ChangeSheet))
+            }
+            ResourcesMsg::DiffDown => {
+                if let Some(diff) = self.diff.as_mut() {
+                    diff.scroll_down();
+                }
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::ShowExport(scope) => {
+                self.export = Some((scope, self.export_form()));
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::HideExport => {
+                self.export = None;
+                Ok(ComponentReturn::empty())
+            }
+            ResourcesMsg::ExportForm(msg) => match self.export.as_mut() {
+                Some((_, form)) => {
+                    Self::forward_update(form, msg.into(), Self::export_form_msg).await
+                }
+                None => Ok(ComponentReturn::empty()),
+            },
+            ResourcesMsg::Export(path) => self.export(path),
+            ResourcesMsg::Exported(path, count) => Ok(ComponentReturn::action(
+                Action::Notification(Notification::info(format!(
+                    "Exported {} {} to {}",
+                    count,
+                    R::title().to_lowercase(),
+                    path
+                ))),
             )),
+            ResourcesMsg::ExportFailed(error) => Ok(ComponentReturn::action(Action::Notification(
+                Notification::error(error),
+            ))),
+            ResourcesMsg::DiffUp => {
+                if let Some(diff) = self.diff.as_mut() {
+                    diff.scroll_up();
+                }
+                Ok(ComponentReturn::empty())
+            }
         }
     }
 
@@ -311,8 +1053,60 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
         &mut self,
         evt: ComponentEvent,
     ) -> anyhow::Result<Vec<ComponentMsg<Self::Msg>>> {
+        if self.following.is_some() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ResourcesMsg::StopFollow.into()])
+                }
+                _ => Ok(vec![]),
+            };
+        }
+
+        if let Some((_, form)) = self.export.as_mut() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ResourcesMsg::HideExport.into()])
+                }
+                evt => Self::forward_event(form, evt, Self::export_form_msg),
+            };
+        }
+
+        if let Some(form) = self.page_form.as_mut() {
+            return match evt {
+                ComponentEvent::Event(Event::Key(key)) if key.code == KeyCode::Esc => {
+                    Ok(vec![ResourcesMsg::HidePageForm.into()])
+                }
+                evt => Self::forward_event(form, evt, Self::page_form_msg),
+            };
+        }
+
         match self.focus {
             Focus::ResourceList => match (evt, self.show_filters) {
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Esc && self.loading.is_some() =>
+                {
+                    Ok(vec![ResourcesMsg::CancelLoadAll.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('0') =>
+                {
+                    Ok(vec![ResourcesMsg::FirstPage.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('$') =>
+                {
+                    Ok(vec![ResourcesMsg::LastPage.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('g') =>
+                {
+                    Ok(vec![ResourcesMsg::ShowPageForm.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('a') =>
+                {
+                    Ok(vec![ResourcesMsg::LoadAll.into()])
+                }
                 (ComponentEvent::Event(Event::Key(key)), false)
                     if key.code == KeyCode::Char('n') =>
                 {
@@ -333,6 +1127,36 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
                 {
                     Ok(vec![ResourcesMsg::ShowFilters.into()])
                 }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('w') && self.on_follow.is_some() =>
+                {
+                    Ok(vec![ResourcesMsg::StartFollow.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('c') && self.copy_kind.is_some() =>
+                {
+                    Ok(vec![ResourcesMsg::Copy.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('m') && self.on_single_fetch.is_some() =>
+                {
+                    Ok(vec![ResourcesMsg::Mark.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('=') && self.on_single_fetch.is_some() =>
+                {
+                    Ok(vec![ResourcesMsg::Diff.into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('e') =>
+                {
+                    Ok(vec![ResourcesMsg::ShowExport(ExportScope::Page).into()])
+                }
+                (ComponentEvent::Event(Event::Key(key)), false)
+                    if key.code == KeyCode::Char('E') =>
+                {
+                    Ok(vec![ResourcesMsg::ShowExport(ExportScope::All).into()])
+                }
                 (ComponentEvent::Event(Event::Key(key)), true) if key.code == KeyCode::Esc => {
                     Ok(vec![ResourcesMsg::HideFilters.into()])
                 }
@@ -352,16 +1176,80 @@ impl<T: TableEntry + Send + Sync + 'static, R: DrawableResource + Send + Sync +
                 }
                 _ => Self::forward_event(&mut self.resource, evt, ResourcesMsg::ResourceMsg),
             },
+            Focus::Diff => match evt {
+                ComponentEvent::Event(Event::Key(k)) => match k.code {
+                    KeyCode::Esc => Ok(vec![ResourcesMsg::Back.into()]),
+                    KeyCode::Char('j') | KeyCode::Down => Ok(vec![ResourcesMsg::DiffDown.into()]),
+                    KeyCode::Char('k') | KeyCode::Up => Ok(vec![ResourcesMsg::DiffUp.into()]),
+                    _ => Ok(vec![]),
+                },
+                _ => Ok(vec![]),
+            },
         }
     }
 }
 
+fn view_form_popup<M: Send + Sync + 'static>(f: &mut Frame, title: &str, form: &mut Form<M>) {
+    let block = Block::default()
+        .title_top(
+            Line::from(Span::styled(
+                format!(" {} ", title),
+                Style::default().fg(Color::Red),
+            ))
+            .centered(),
+        )
+        .borders(Borders::ALL);
+    let area = popup_area(f.area(), 50, 20);
+    let content = block.inner(area);
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    form.view(f, content);
+}
+
 pub trait DrawableResource {
     fn id(&self) -> &str;
 
     fn title() -> &'static str;
 
     fn fields(&self) -> Vec<Field>;
+
+    fn state(&self) -> Option<String> {
+        None
+    }
+
+    fn state_timestamp(&self) -> Option<i64> {
+        None
+    }
+
+    /// The codes of the states when the API stores them as numbers.
+    fn state_codes() -> StateCodes {
+        &[]
+    }
+
+    fn created_at(&self) -> Option<i64> {
+        None
+    }
+
+    /// The fields as a JSON object, JSON values are kept structured.
+    fn to_json(&self) -> Value {
+        Value::Object(
+            self.fields()
+                .into_iter()
+                .map(|field| (field.name, field.value.to_json()))
+                .collect(),
+        )
+    }
+}
+
+pub fn screaming_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (idx, c) in name.chars().enumerate() {
+        if c.is_uppercase() && idx > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
 }
 
 pub struct Field {
@@ -387,6 +1275,14 @@ impl Field {
             value: FieldValue::Json(serde_json::to_string_pretty(value).unwrap()),
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &FieldValue {
+        &self.value
+    }
 }
 
 pub enum FieldValue {
@@ -394,6 +1290,17 @@ pub enum FieldValue {
     Json(String),
 }
 
+impl FieldValue {
+    pub fn to_json(&self) -> Value {
+        match self {
+            FieldValue::Str(s) => Value::String(s.clone()),
+            FieldValue::Json(s) => {
+                serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.clone()))
+            }
+        }
+    }
+}
+
 impl AsRef<str> for FieldValue {
     fn as_ref(&self) -> &str {
         match self {
@@ -0,0 +1,336 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use serde_json::Value;
+
+use crate::{
+    components::Component,
+    types::manifest::{changed_values, Change},
+};
+
+/// A resource marked for comparison, as built by `DrawableResource::to_json`.
+#[derive(Debug, Clone)]
+pub struct DiffSide {
+    label: String,
+    value: Value,
+}
+
+impl DiffSide {
+    pub fn new(connector: &str, id: &str, value: Value) -> Self {
+        Self {
+            label: format!("{}/{}", connector, id),
+            value,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// The keys leading to the value a line belongs to.
+type Path = Vec<String>;
+
+/// A line of the diff, empty on the side missing the value the other side shows.
+#[derive(Debug)]
+struct DiffRow {
+    left: Option<(Path, String)>,
+    right: Option<(Path, String)>,
+}
+
+/// Two resources of the same type rendered side by side, the differing keys highlighted.
+#[derive(Debug)]
+pub struct ResourceDiff {
+    left: DiffSide,
+    right: DiffSide,
+    changes: Vec<Change>,
+    rows: Vec<DiffRow>,
+    scroll: u16,
+    /// Lines shown by the last render, bounding the scroll.
+    height: u16,
+}
+
+impl ResourceDiff {
+    pub fn new(left: DiffSide, right: DiffSide) -> Self {
+        let changes = changed_values(&left.value, &right.value, "");
+        let mut rows = vec![];
+        aligned_lines(
+            Some(&left.value),
+            Some(&right.value),
+            &[],
+            None,
+            0,
+            (true, true),
+            &mut rows,
+        );
+        Self {
+            left,
+            right,
+            changes,
+            rows,
+            scroll: 0,
+            height: 0,
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1).min(self.max_scroll());
+    }
+
+    fn max_scroll(&self) -> u16 {
+        let rows = u16::try_from(self.rows.len()).unwrap_or(u16::MAX);
+        rows.saturating_sub(self.height)
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    fn side<'a>(
+        &'a self,
+        side: &'a DiffSide,
+        line: fn(&DiffRow) -> Option<&(Path, String)>,
+        color: Color,
+    ) -> Paragraph<'a> {
+        let changed = self
+            .changes
+            .iter()
+            .map(|c| c.keys.as_slice())
+            .collect::<Vec<_>>();
+
+        let lines = self
+            .rows
+            .iter()
+            .map(|row| match line(row) {
+                Some((path, text)) if is_changed(&changed, path) => Line::styled(
+                    text.as_str(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Some((_, text)) => Line::raw(text.as_str()),
+                None => Line::default(),
+            })
+            .collect::<Vec<_>>();
+
+        Paragraph::new(lines).scroll((self.scroll, 0)).block(
+            Block::default()
+                .title(Span::styled(side.label(), Style::default().fg(color)))
+                .borders(Borders::TOP),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for ResourceDiff {
+    type Msg = ();
+    type Props = ();
+
+    fn view(&mut self, f: &mut Frame, rect: Rect) {
+        let title = match self.changes.len() {
+            0 => String::from(" Diff: identical "),
+            1 => String::from(" Diff: 1 changed value "),
+            n => format!(" Diff: {} changed values ", n),
+        };
+        let block = Block::default()
+            .title_top(Line::from(Span::styled(title, Style::default().fg(Color::Cyan))).centered())
+            .borders(Borders::ALL);
+        let area = block.inner(rect);
+        f.render_widget(block, rect);
+
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(area);
+        // Each side has a top border for its label.
+        self.height = area.height.saturating_sub(1);
+        self.scroll = self.scroll.min(self.max_scroll());
+        f.render_widget(
+            self.side(&self.left, |row| row.left.as_ref(), Color::Red),
+            left,
+        );
+        f.render_widget(
+            self.side(&self.right, |row| row.right.as_ref(), Color::Green),
+            right,
+        );
+    }
+}
+
+/// A value changed itself, or sits below a changed object missing on the other side. A change
+/// without keys means the resources differ as a whole.
+fn is_changed(changed: &[&[String]], path: &[String]) -> bool {
+    changed.iter().any(|keys| path.starts_with(keys))
+}
+
+/// The lines of both values with the same path on the same row, objects present on both sides
+/// being merged key by key.
+fn aligned_lines(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    path: &[String],
+    key: Option<&str>,
+    indent: usize,
+    last: (bool, bool),
+    out: &mut Vec<DiffRow>,
+) {
+    if let (Some(Value::Object(l)), Some(Value::Object(r))) = (left, right) {
+        if !l.is_empty() || !r.is_empty() {
+            let pad = "  ".repeat(indent);
+            let prefix = key
+                .map(|k| format!("{}{}: ", pad, Value::String(k.to_string())))
+                .unwrap_or_else(|| pad.clone());
+            let line = |text: String| Some((path.to_vec(), text));
+            out.push(DiffRow {
+                left: line(format!("{}{{", prefix)),
+                right: line(format!("{}{{", prefix)),
+            });
+
+            let mut keys = l.keys().chain(r.keys()).collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            let left_last = keys.iter().rev().find(|k| l.contains_key(**k)).copied();
+            let right_last = keys.iter().rev().find(|k| r.contains_key(**k)).copied();
+            for k in keys {
+                let child = child_path(path, k);
+                let is_last = (left_last == Some(k), right_last == Some(k));
+                aligned_lines(
+                    l.get(k),
+                    r.get(k),
+                    &child,
+                    Some(k),
+                    indent + 1,
+                    is_last,
+                    out,
+                );
+            }
+
+            let comma = |last: bool| if last { "" } else { "," };
+            out.push(DiffRow {
+                left: line(format!("{}}}{}", pad, comma(last.0))),
+                right: line(format!("{}}}{}", pad, comma(last.1))),
+            });
+            return;
+        }
+    }
+
+    let mut left_lines = vec![];
+    let mut right_lines = vec![];
+    if let Some(value) = left {
+        json_lines(value, path, key, indent, last.0, &mut left_lines);
+    }
+    if let Some(value) = right {
+        json_lines(value, path, key, indent, last.1, &mut right_lines);
+    }
+    let len = left_lines.len().max(right_lines.len());
+    let mut left_lines = left_lines.into_iter();
+    let mut right_lines = right_lines.into_iter();
+    for _ in 0..len {
+        out.push(DiffRow {
+            left: left_lines.next(),
+            right: right_lines.next(),
+        });
+    }
+}
+
+fn child_path(path: &[String], key: &str) -> Path {
+    let mut child = path.to_vec();
+    child.push(key.to_string());
+    child
+}
+
+/// Pretty printed JSON lines with the path of the value each line belongs to, arrays being
+/// compared as a whole like in `changed_values`.
+fn json_lines(
+    value: &Value,
+    path: &[String],
+    key: Option<&str>,
+    indent: usize,
+    last: bool,
+    out: &mut Vec<(Path, String)>,
+) {
+    let pad = "  ".repeat(indent);
+    let prefix = key
+        .map(|k| format!("{}{}: ", pad, Value::String(k.to_string())))
+        .unwrap_or_else(|| pad.clone());
+    let comma = if last { "" } else { "," };
+
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            out.push((path.to_vec(), format!("{}{{", prefix)));
+            let len = map.len();
+            for (idx, (k, v)) in map.iter().enumerate() {
+                let child = child_path(path, k);
+                json_lines(v, &child, Some(k), indent + 1, idx + 1 == len, out);
+            }
+            out.push((path.to_vec(), format!("{}}}{}", pad, comma)));
+        }
+        value => {
+            let text = serde_json::to_string_pretty(value).unwrap_or_default();
+            let mut lines = text.lines().peekable();
+            let mut first = true;
+            while let Some(line) = lines.next() {
+                let start = if first { prefix.clone() } else { pad.clone() };
+                let end = if lines.peek().is_none() { comma } else { "" };
+                out.push((path.to_vec(), format!("{}{}{}", start, line, end)));
+                first = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn diff(left: Value, right: Value) -> ResourceDiff {
+        ResourceDiff::new(
+            DiffSide::new("a", "1", left),
+            DiffSide::new("b", "1", right),
+        )
+    }
+
+    fn changed_lines(diff: &ResourceDiff) -> Vec<&str> {
+        let changed = diff
+            .changes
+            .iter()
+            .map(|c| c.keys.as_slice())
+            .collect::<Vec<_>>();
+        diff.rows
+            .iter()
+            .filter_map(|row| row.left.as_ref())
+            .filter(|(path, _)| is_changed(&changed, path))
+            .map(|(_, text)| text.trim())
+            .collect()
+    }
+
+    #[test]
+    fn keys_with_dots_are_not_nested() {
+        let diff = diff(
+            json!({ "edc:name": 1, "edc:name.v2": 2, "nested": { "a": 1, "b": 2 } }),
+            json!({ "edc:name": 3, "edc:name.v2": 2, "nested": { "a": 4, "b": 2 } }),
+        );
+        assert_eq!(changed_lines(&diff), vec!["\"edc:name\": 1,", "\"a\": 1,"]);
+    }
+
+    #[test]
+    fn whole_resource_changes() {
+        let diff = diff(json!([1]), json!({ "a": 1 }));
+        assert_eq!(changed_lines(&diff), vec!["[", "1", "]"]);
+    }
+
+    #[test]
+    fn scroll_stops_at_the_last_row() {
+        let mut diff = diff(json!({ "a": 1, "b": 2, "c": 3 }), json!({}));
+        diff.height = 2;
+        for _ in 0..10 {
+            diff.scroll_down();
+        }
+        assert_eq!(diff.scroll, diff.rows.len() as u16 - 2);
+        diff.height = 10;
+        diff.scroll_down();
+        assert_eq!(diff.scroll, 0);
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use anyhow::bail;
+
+use crate::components::table::TableEntry;
+
+use super::DrawableResource;
+
+/// Whether the current page or every page matching the query is exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportScope {
+    Page,
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Ndjson,
+}
+
+impl ExportScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportScope::Page => "Export page",
+            ExportScope::All => "Export all pages",
+        }
+    }
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("csv") => Ok(ExportFormat::Csv),
+            Some("json") => Ok(ExportFormat::Json),
+            Some("ndjson") | Some("jsonl") => Ok(ExportFormat::Ndjson),
+            _ => bail!("Unsupported export format, use a .csv, .json or .ndjson file"),
+        }
+    }
+}
+
+/// Writes the elements with the table columns for CSV, or the full entities for JSON and NDJSON.
+pub fn write<T: TableEntry + DrawableResource>(path: &Path, elements: &[T]) -> anyhow::Result<()> {
+    let content = match ExportFormat::from_path(path)? {
+        ExportFormat::Csv => std::iter::once(csv_line(T::columns()))
+            .chain(elements.iter().map(|element| csv_line(element.cells())))
+            .collect::<String>(),
+        ExportFormat::Json => {
+            let values = elements.iter().map(T::to_json).collect::<Vec<_>>();
+            format!("{}\n", serde_json::to_string_pretty(&values)?)
+        }
+        ExportFormat::Ndjson => elements
+            .iter()
+            .map(|element| Ok(format!("{}\n", serde_json::to_string(&element.to_json())?)))
+            .collect::<anyhow::Result<String>>()?,
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+fn csv_line<S: AsRef<str>>(cells: Vec<S>) -> String {
+    let line = cells
+        .iter()
+        .map(|cell| {
+            let cell = cell.as_ref();
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}\n", line)
+}
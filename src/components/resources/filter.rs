@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use edc_connector_client::types::query::{Query, SortOrder};
 use ratatui::{
-    layout::{Constraint, Flex, Layout, Rect},
+    layout::Rect,
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear},
@@ -10,7 +10,10 @@ use ratatui::{
 };
 
 use crate::{
-    components::{Component, ComponentEvent, ComponentMsg, ComponentReturn},
+    components::{popup_area, Component, ComponentEvent, ComponentMsg, ComponentReturn},
+    types::query::{
+        decode_states, encode_states, format_expression, parse_expression, QueryValue, StateCodes,
+    },
     widgets::form::{
         msg::FormMsg, row::RowField, text::TextField, ChangeSet, FieldComponent, Form,
     },
@@ -22,19 +25,28 @@ pub struct Filter<M> {
     query: Query,
     on_confirm: Option<OnConfirm<M>>,
     form: Form<FilterFormMsg>,
+    state_codes: StateCodes,
 }
 
 impl<M> Filter<M> {
     pub fn new(query: Query) -> Self {
-        let form = Self::form(&query);
+        let form = Self::form(&query, &[]);
         Self {
             query,
             on_confirm: None,
             form,
+            state_codes: &[],
         }
     }
 
-    fn form(query: &Query) -> Form<FilterFormMsg> {
+    /// Lets the filter compare `state` by name for entities stored with numeric states.
+    pub fn with_state_codes(mut self, state_codes: StateCodes) -> Self {
+        self.form = Self::form(&self.query, state_codes);
+        self.state_codes = state_codes;
+        self
+    }
+
+    fn form(query: &Query, state_codes: StateCodes) -> Form<FilterFormMsg> {
         Form::default()
             .field(
                 TextField::builder()
@@ -45,74 +57,36 @@ impl<M> Filter<M> {
                     .build()
                     .unwrap(),
             )
-            .field(
-                TextField::builder()
-                    .name("sort_field".to_string())
-                    .label("Sort Field".to_string())
-                    .build()
-                    .unwrap(),
-            )
-            .field(
-                TextField::builder()
-                    .name("sort_order".to_string())
-                    .label("Sort Order".to_string())
-                    .initial_value("ASC".to_string())
-                    .build()
-                    .unwrap(),
-            )
             .field(
                 RowField::default()
-                    .name("filter_0")
-                    .field(
-                        TextField::builder()
-                            .name("left_operand".to_string())
-                            .label("LeftOperand".to_string())
-                            .build()
-                            .unwrap(),
-                    )
+                    .name("sort")
                     .field(
                         TextField::builder()
-                            .name("operator".to_string())
-                            .label("Operator".to_string())
-                            .initial_value("=".to_string())
+                            .name("sort_field".to_string())
+                            .label("Sort Field".to_string())
                             .build()
                             .unwrap(),
                     )
                     .field(
                         TextField::builder()
-                            .name("right_operand".to_string())
-                            .label("RightOperand".to_string())
+                            .name("sort_order".to_string())
+                            .label("Sort Order".to_string())
+                            .initial_value("ASC".to_string())
                             .build()
                             .unwrap(),
                     ),
             )
             .field(
-                RowField::default()
-                    .name("filter_1")
-                    .field(
-                        TextField::builder()
-                            .name("left_operand".to_string())
-                            .label("LeftOperand".to_string())
-                            .build()
-                            .unwrap(),
-                    )
-                    .field(
-                        TextField::builder()
-                            .name("operator".to_string())
-                            .label("Operator".to_string())
-                            .initial_value("=".to_string())
-                            .build()
-                            .unwrap(),
-                    )
-                    .field(
-                        TextField::builder()
-                            .name("right_operand".to_string())
-                            .label("RightOperand".to_string())
-                            .build()
-                            .unwrap(),
-                    ),
+                TextField::builder()
+                    .name("filter".to_string())
+                    .label("Filter (e.g. state = STARTED and assetId like 'foo%')".to_string())
+                    .initial_value(filter_expression(query, state_codes))
+                    .build()
+                    .unwrap(),
             )
-            .on_confirm(|fields| Self::parse_fields(fields).map(FilterFormMsg::Changed))
+            .on_confirm(move |fields| {
+                Self::parse_fields(fields, state_codes).map(FilterFormMsg::Changed)
+            })
     }
 
     pub fn on_confirm(mut self, cb: impl Fn(Query) -> M + Send + Sync + 'static) -> Self {
@@ -120,41 +94,27 @@ impl<M> Filter<M> {
         self
     }
 
-    pub fn next_page(&mut self) {
-        self.query = self
-            .query
-            .to_builder()
-            .offset(self.query.offset() + self.query.limit())
-            .build();
-    }
-
-    pub fn prev_page(&mut self) {
-        self.query = self
-            .query
-            .to_builder()
-            .offset(self.query.offset() - self.query.limit())
-            .build();
-    }
-
-    fn popup_area(&self, area: Rect, percent_x: u16, percent_y: u16) -> Rect {
-        let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
-        let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
-        let [area] = vertical.areas(area);
-        let [area] = horizontal.areas(area);
-        area
-    }
-
-    fn parse_fields(fields: HashMap<String, FieldComponent>) -> anyhow::Result<Query> {
+    fn parse_fields(
+        fields: HashMap<String, FieldComponent>,
+        state_codes: StateCodes,
+    ) -> anyhow::Result<Query> {
         let limit: String = fields["limit"].clone().try_into()?;
-        let sort_field: String = fields["sort_field"].clone().try_into()?;
-        let sort_order = fields["sort_order"]
-            .clone()
-            .try_into()
-            .and_then(|order: String| match order.as_str() {
-                "ASC" => Ok(SortOrder::Asc),
-                "DESC" => Ok(SortOrder::Desc),
-                wrong => anyhow::bail!("Sort order {} not supported, expected ASC or DESC", wrong),
-            })?;
+        let FieldComponent::Row(sort) = fields["sort"].clone() else {
+            anyhow::bail!("Cannot extract the sort from a text field")
+        };
+        let sort = sort.as_map();
+        let sort_field: String = sort["sort_field"].clone().try_into()?;
+        let sort_order =
+            sort["sort_order"]
+                .clone()
+                .try_into()
+                .and_then(|order: String| match order.as_str() {
+                    "ASC" => Ok(SortOrder::Asc),
+                    "DESC" => Ok(SortOrder::Desc),
+                    wrong => {
+                        anyhow::bail!("Sort order {} not supported, expected ASC or DESC", wrong)
+                    }
+                })?;
 
         let mut query = Query::builder().limit(limit.parse()?);
 
@@ -162,15 +122,13 @@ impl<M> Filter<M> {
             query = query.sort(&sort_field, sort_order);
         }
 
-        let filter_1: Criteria = fields["filter_0"].clone().try_into()?;
-        let filter_2: Criteria = fields["filter_1"].clone().try_into()?;
-
-        if filter_1.is_valid() {
-            query = query.filter(&filter_1.field, &filter_1.operator, filter_1.value);
-        }
-
-        if filter_2.is_valid() {
-            query = query.filter(&filter_2.field, &filter_2.operator, filter_2.value);
+        let filter: String = fields["filter"].clone().try_into()?;
+        for condition in encode_states(parse_expression(&filter)?, state_codes)? {
+            query = query.filter(
+                &condition.field,
+                &condition.operator,
+                QueryValue::new(condition.value),
+            );
         }
 
         Ok(query.build())
@@ -179,30 +137,26 @@ impl<M> Filter<M> {
     pub fn set_query(&mut self, query: Query) -> anyhow::Result<()> {
         self.form.change_field("limit", query.limit())?;
 
-        if let Some(s) = query.sort() {
-            self.form.change_field("sort_field", s.field())?;
-
-            let order = match s.order() {
-                SortOrder::Asc => "ASC",
-                SortOrder::Desc => "DESC",
-            };
-            self.form.change_field("sort_order", order)?;
-        } else {
-            self.form.change_field("sort_order", "ASC")?;
-        }
-
-        for (idx, f) in query.filter_expression().iter().enumerate() {
-            let value: String = f.operand_right().try_from()?;
+        let (field, order) = match query.sort() {
+            Some(s) => (
+                s.field(),
+                match s.order() {
+                    SortOrder::Asc => "ASC",
+                    SortOrder::Desc => "DESC",
+                },
+            ),
+            None => ("", "ASC"),
+        };
+        self.form.change_field(
+            "sort",
+            ChangeSet::row(vec![
+                ("sort_field", field.into()),
+                ("sort_order", order.into()),
+            ]),
+        )?;
 
-            self.form.change_field(
-                &format!("filter_{}", idx),
-                ChangeSet::row(vec![
-                    ("left_operand", f.operand_left().into()),
-                    ("operator", f.operator().into()),
-                    ("right_operand", value.into()),
-                ]),
-            )?;
-        }
+        self.form
+            .change_field("filter", filter_expression(&query, self.state_codes))?;
 
         Ok(())
     }
@@ -274,7 +228,7 @@ impl<M: Send + Sync + 'static> Component for Filter<M> {
         let block = Block::default()
             .title_top(Line::from(styled_text).centered())
             .borders(Borders::ALL);
-        let area = self.popup_area(area, 30, 50);
+        let area = popup_area(area, 50, 50);
 
         let content = block.inner(area);
         f.render_widget(Clear, area); //this clears out the background
@@ -284,34 +238,24 @@ impl<M: Send + Sync + 'static> Component for Filter<M> {
     }
 }
 
-struct Criteria {
-    field: String,
-    operator: String,
-    value: String,
-}
-
-impl Criteria {
-    fn is_valid(&self) -> bool {
-        !self.field.is_empty() && !self.operator.is_empty() && !self.value.is_empty()
-    }
-}
-
-impl TryInto<Criteria> for FieldComponent {
-    type Error = anyhow::Error;
-
-    fn try_into(self) -> Result<Criteria, Self::Error> {
-        match self {
-            FieldComponent::Text(_) => {
-                anyhow::bail!("Cannot extract a filter from text field")
-            }
-            FieldComponent::Row(row_field) => {
-                let fields = row_field.as_map();
-                Ok(Criteria {
-                    field: fields["left_operand"].clone().try_into()?,
-                    operator: fields["operator"].clone().try_into()?,
-                    value: fields["right_operand"].clone().try_into()?,
-                })
-            }
-        }
-    }
+/// The criteria of the query in the filter expression syntax, with state names instead of codes.
+pub fn filter_expression(query: &Query, state_codes: StateCodes) -> String {
+    let values = query
+        .filter_expression()
+        .iter()
+        .map(|criterion| {
+            decode_states(
+                criterion.operand_left(),
+                &criterion.operand_right().0,
+                state_codes,
+            )
+        })
+        .collect::<Vec<_>>();
+    format_expression(
+        query
+            .filter_expression()
+            .iter()
+            .zip(values.iter())
+            .map(|(criterion, value)| (criterion.operand_left(), criterion.operator(), value)),
+    )
 }
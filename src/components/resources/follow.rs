@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, Paragraph},
+    Frame,
+};
+
+use crate::components::{popup_area, Component};
+
+pub const FOLLOW_INTERVAL: Duration = Duration::from_secs(2);
+pub const FOLLOW_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone)]
+pub struct FollowState {
+    id: String,
+    state: String,
+    state_timestamp: Option<i64>,
+    terminal: bool,
+}
+
+impl FollowState {
+    pub fn new(
+        id: impl Into<String>,
+        state: impl Into<String>,
+        state_timestamp: Option<i64>,
+        terminal: bool,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            state: state.into(),
+            state_timestamp,
+            terminal,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn state_timestamp(&self) -> Option<i64> {
+        self.state_timestamp
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+
+    /// Negotiations and transfer processes both end in `TERMINATED` when they fail.
+    pub fn is_failed(&self) -> bool {
+        self.state == "TERMINATED"
+    }
+}
+
+#[derive(Debug)]
+struct Observation {
+    at: DateTime<Local>,
+    state: String,
+}
+
+#[derive(Debug)]
+enum FollowOutcome {
+    Done(String),
+    TimedOut,
+    Failed(String),
+}
+
+#[derive(Debug)]
+pub struct Follower {
+    session: u64,
+    id: Option<String>,
+    observations: Vec<Observation>,
+    started: Instant,
+    outcome: Option<FollowOutcome>,
+}
+
+impl Default for Follower {
+    fn default() -> Self {
+        Self {
+            session: 0,
+            id: None,
+            observations: vec![],
+            started: Instant::now(),
+            outcome: None,
+        }
+    }
+}
+
+impl Follower {
+    pub fn start(&mut self) -> u64 {
+        self.session += 1;
+        self.id = None;
+        self.observations.clear();
+        self.started = Instant::now();
+        self.outcome = None;
+        self.session
+    }
+
+    pub fn stop(&mut self) {
+        self.session += 1;
+    }
+
+    pub fn is_current(&self, session: u64) -> bool {
+        self.session == session && self.outcome.is_none()
+    }
+
+    pub fn observe(&mut self, state: &FollowState) {
+        self.id = Some(state.id().to_string());
+
+        let changed = self
+            .observations
+            .last()
+            .map(|last| last.state != state.state())
+            .unwrap_or(true);
+
+        if changed {
+            self.observations.push(Observation {
+                at: Local::now(),
+                state: state.state().to_string(),
+            });
+        }
+
+        if state.is_terminal() {
+            self.outcome = Some(FollowOutcome::Done(state.state().to_string()));
+        } else if self.started.elapsed() >= FOLLOW_TIMEOUT {
+            self.outcome = Some(FollowOutcome::TimedOut);
+        }
+    }
+
+    pub fn fail(&mut self, error: String) {
+        self.outcome = Some(FollowOutcome::Failed(error));
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self.outcome, Some(FollowOutcome::TimedOut))
+    }
+
+    pub fn id(&self) -> &str {
+        self.id.as_deref().unwrap_or("N/A")
+    }
+
+    fn timeline(&self) -> List<'_> {
+        let first = self.observations.first().map(|o| o.at);
+        self.observations
+            .iter()
+            .map(|o| {
+                let elapsed = first
+                    .map(|f| (o.at - f).num_milliseconds() as f64 / 1000.0)
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", o.at.format("%H:%M:%S")),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::raw(format!("{:<20}", o.state)),
+                    Span::styled(
+                        format!("+{:.1}s", elapsed),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ])
+            })
+            .collect()
+    }
+
+    fn status(&self) -> Line<'_> {
+        match self.outcome.as_ref() {
+            None => Line::styled(
+                format!(
+                    "Polling every {}s ({}s elapsed)",
+                    FOLLOW_INTERVAL.as_secs(),
+                    self.started.elapsed().as_secs()
+                ),
+                Style::default().fg(Color::Cyan),
+            ),
+            Some(FollowOutcome::Done(state)) => Line::styled(
+                format!("Reached terminal state {}", state),
+                Style::default().fg(Color::Green),
+            ),
+            Some(FollowOutcome::TimedOut) => Line::styled(
+                format!("Timed out after {}s", FOLLOW_TIMEOUT.as_secs()),
+                Style::default().fg(Color::Red),
+            ),
+            Some(FollowOutcome::Failed(err)) => {
+                Line::styled(format!("Failed: {}", err), Style::default().fg(Color::Red))
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Component for Follower {
+    type Msg = ();
+    type Props = ();
+
+    fn view(&mut self, f: &mut Frame, _rect: Rect) {
+        let styled_text = Span::styled(
+            format!(" Following {} ", self.id()),
+            Style::default().fg(Color::Red),
+        );
+        let block = Block::default()
+            .title_top(Line::from(styled_text).centered())
+            .borders(Borders::ALL);
+        let area = popup_area(f.area(), 50, 50);
+
+        let content = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let [timeline, status] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(2)]).areas(content);
+
+        f.render_widget(self.timeline(), timeline);
+        f.render_widget(
+            Paragraph::new(self.status())
+                .centered()
+                .block(Block::default().borders(Borders::TOP)),
+            status,
+        );
+    }
+}
@@ -1,8 +1,12 @@
 use edc_connector_client::types::query::Query;
 
-use crate::components::table::msg::TableMsg;
+use crate::{
+    components::table::msg::TableMsg, types::api_error::ApiError, widgets::form::msg::FormMsg,
+};
 
-use super::{filter::FilterMsg, resource::msg::ResourceMsg};
+use super::{
+    export::ExportScope, filter::FilterMsg, follow::FollowState, resource::msg::ResourceMsg,
+};
 
 #[derive(Debug)]
 pub enum ResourcesMsg<T, R> {
@@ -12,6 +16,17 @@ pub enum ResourcesMsg<T, R> {
     NextPage,
     PrevPage,
     RefreshPage,
+    FirstPage,
+    LastPage,
+    LastPageFetched(u32, Vec<T>),
+    ShowPageForm,
+    HidePageForm,
+    PageForm(FormMsg<u32>),
+    GoToPage(u32),
+    LoadAll,
+    AllPageFetched(u64, Vec<T>),
+    LoadAllFailed(u64),
+    CancelLoadAll,
     ShowFilters,
     HideFilters,
     ChangeQuery(Query),
@@ -19,5 +34,23 @@ pub enum ResourcesMsg<T, R> {
     FilterMsg(FilterMsg<Box<ResourcesMsg<T, R>>>),
     ResourceMsg(ResourceMsg),
     ResourcesFetched(Vec<T>),
-    ResourcesFetchFailed(String),
+    ResourcesFetchFailed(ApiError),
+    Unauthorized,
+    StartFollow,
+    StopFollow,
+    FollowStateFetched(u64, FollowState),
+    FollowFailed(u64, String),
+    Copy,
+    Mark,
+    Marked(R),
+    Diff,
+    DiffFetched(R),
+    DiffDown,
+    DiffUp,
+    ShowExport(ExportScope),
+    HideExport,
+    ExportForm(FormMsg<String>),
+    Export(String),
+    Exported(String, usize),
+    ExportFailed(String),
 }
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+/// The number of resources matching the query, exact once a short page has been fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Total {
+    Exact(u32),
+    AtLeast(u32),
+}
+
+impl Total {
+    /// The total known after fetching `len` resources at `offset` with pages of `limit`.
+    pub fn observe(previous: Option<Total>, offset: u32, len: u32, limit: u32) -> Option<Total> {
+        let seen = offset + len;
+        match previous {
+            _ if len < limit && (len > 0 || offset == 0) => Some(Total::Exact(seen)),
+            // An empty page past the end tells nothing new.
+            previous if len == 0 => previous,
+            Some(Total::Exact(total)) if total >= seen => Some(Total::Exact(total)),
+            Some(Total::AtLeast(total)) => Some(Total::AtLeast(total.max(seen))),
+            _ => Some(Total::AtLeast(seen)),
+        }
+    }
+
+    /// The offset of the last page, when the total is exact.
+    pub fn last_offset(&self, limit: u32) -> Option<u32> {
+        match self {
+            Total::Exact(total) if limit > 0 => Some(total.saturating_sub(1) / limit * limit),
+            _ => None,
+        }
+    }
+
+    pub fn pages(&self, limit: u32) -> String {
+        match self {
+            Total::Exact(total) if limit > 0 => total.div_ceil(limit).max(1).to_string(),
+            Total::Exact(_) => String::from("1"),
+            Total::AtLeast(total) if limit > 0 => format!("{}+", total.div_ceil(limit)),
+            Total::AtLeast(_) => String::from("?"),
+        }
+    }
+}
+
+impl Display for Total {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Total::Exact(total) => write!(f, "{}", total),
+            Total::AtLeast(total) => write!(f, "{}+", total),
+        }
+    }
+}
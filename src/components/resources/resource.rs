@@ -8,7 +8,7 @@ use ratatui::{
     layout::{Constraint, Layout, Rect, Size},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
+    widgets::{Block, BorderType, Borders, List, Paragraph, StatefulWidget, Widget},
     Frame,
 };
 use tui_scrollview::{ScrollView, ScrollViewState};
@@ -17,11 +17,15 @@ pub mod msg;
 use super::{Component, DrawableResource, Field, FieldValue};
 use crate::{
     components::{Action, ComponentEvent, ComponentMsg, ComponentReturn, Notification},
-    types::info::InfoSheet,
+    types::{
+        history::{format_duration, format_timestamp, StateObservation},
+        info::InfoSheet,
+    },
 };
 
 pub struct ResourceComponent<T> {
     resource: Option<T>,
+    history: Vec<StateObservation>,
     name: String,
     selected_field: usize,
     clip: Clipboard,
@@ -41,6 +45,7 @@ impl<T> Default for ResourceComponent<T> {
     fn default() -> Self {
         Self {
             resource: Default::default(),
+            history: vec![],
             name: String::default(),
             selected_field: 0,
             clip: Clipboard::new().unwrap(),
@@ -63,6 +68,7 @@ impl<T: DrawableResource> ResourceComponent<T> {
         Self {
             name,
             resource: None,
+            history: vec![],
             selected_field: 0,
             clip: Clipboard::new().unwrap(),
             scroll_view_state: ScrollViewState::default(),
@@ -72,6 +78,72 @@ impl<T: DrawableResource> ResourceComponent<T> {
     pub fn update_resource(&mut self, resource: Option<T>) {
         self.resource = resource;
     }
+
+    pub fn update_history(&mut self, history: Vec<StateObservation>) {
+        self.history = history;
+    }
+
+    fn has_timeline(&self) -> bool {
+        self.resource
+            .as_ref()
+            .map(|res| res.state().is_some())
+            .unwrap_or(false)
+    }
+
+    fn render_timeline(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title_top(Line::from(" State history ").centered())
+            .borders(Borders::LEFT);
+
+        let mut lines = vec![];
+
+        if let Some(created_at) = self.resource.as_ref().and_then(|res| res.created_at()) {
+            lines.push(Line::from(vec![
+                Span::styled("created  ", Style::default().fg(Color::Yellow)),
+                Span::raw(format_timestamp(created_at)),
+            ]));
+        }
+
+        let mut previous: Option<&StateObservation> = None;
+        for observation in self.history.iter() {
+            let elapsed = previous
+                .map(|p| {
+                    format!(
+                        "+{}",
+                        format_duration(observation.timestamp() - p.timestamp())
+                    )
+                })
+                .unwrap_or_default();
+            let source = if observation.is_reported() {
+                "server"
+            } else {
+                "seen"
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", format_timestamp(observation.timestamp())),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(format!("{:<7}", source), Style::default().fg(Color::Gray)),
+                Span::raw(format!("{:<14}", observation.state())),
+                Span::styled(elapsed, Style::default().fg(Color::Gray)),
+            ]));
+            previous = Some(observation);
+        }
+
+        if let (Some(first), Some(last)) = (self.history.first(), self.history.last()) {
+            lines.push(Line::from(vec![
+                Span::styled("total    ", Style::default().fg(Color::Yellow)),
+                Span::raw(format_duration(last.timestamp() - first.timestamp())),
+            ]));
+            lines.push(Line::from(Span::styled(
+                "server: connector time, seen: observed locally",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+
+        f.render_widget(List::new(lines).block(block), area);
+    }
     fn fields_height(&self) -> u16 {
         if let Some(res) = self.resource.as_ref() {
             res.fields()
@@ -191,7 +263,15 @@ impl<T: DrawableResource + Send> Component for ResourceComponent<T> {
             .title_top(Line::from(styled_text).centered())
             .borders(Borders::ALL);
 
-        let area = block.inner(rect);
+        let mut area = block.inner(rect);
+
+        if self.has_timeline() {
+            let [fields, timeline] =
+                Layout::horizontal([Constraint::Percentage(65), Constraint::Percentage(35)])
+                    .areas(area);
+            self.render_timeline(f, timeline);
+            area = fields;
+        }
 
         let mut scroll_view = ScrollView::new(Size::new(area.width - 1, self.fields_height()));
 
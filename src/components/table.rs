@@ -49,8 +49,18 @@ impl<T: TableEntry, M> Default for UiTable<T, M> {
 }
 
 pub trait TableEntry {
-    fn row(&self) -> Row<'_>;
-    fn headers() -> Row<'static>;
+    /// The column values as plain text, also used when exporting to CSV.
+    fn cells(&self) -> Vec<String>;
+
+    fn columns() -> Vec<&'static str>;
+
+    fn row(&self) -> Row<'_> {
+        Row::new(self.cells())
+    }
+
+    fn headers() -> Row<'static> {
+        Row::new(Self::columns())
+    }
 }
 
 #[async_trait::async_trait]
@@ -179,7 +189,23 @@ impl<T: TableEntry, M> UiTable<T, M> {
         self.table_state.select(Some(new_pos))
     }
 
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.elements.len() {
+            self.table_state.select(Some(idx));
+        }
+    }
+
     pub fn elements(&self) -> &[T] {
         &self.elements
     }
+
+    pub fn elements_mut(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.table_state
+            .selected()
+            .and_then(|idx| self.elements.get(idx))
+    }
 }
@@ -1,7 +1,9 @@
-use edc_connector_client::types::transfer_process::TransferProcess;
-use ratatui::widgets::Row;
+use edc_connector_client::types::transfer_process::{TransferProcess, TransferProcessState};
 
-use crate::components::resources::FieldValue;
+use crate::{
+    components::resources::{follow::FollowState, screaming_snake_case, FieldValue},
+    types::query::StateCodes,
+};
 
 use super::{
     resources::{msg::ResourcesMsg, DrawableResource, Field, ResourcesComponent},
@@ -11,10 +13,54 @@ use super::{
 #[derive(Debug, Clone)]
 pub struct TransferProcessEntry(TransferProcess);
 
+pub const STATE_CODES: &[(&str, u32)] = &[
+    ("INITIAL", 100),
+    ("PROVISIONING", 200),
+    ("PROVISIONING_REQUESTED", 250),
+    ("PROVISIONED", 300),
+    ("REQUESTING", 400),
+    ("REQUESTED", 500),
+    ("STARTING", 550),
+    ("STARTED", 600),
+    ("SUSPENDING", 650),
+    ("SUSPENDED", 700),
+    ("RESUMING", 720),
+    ("COMPLETING", 750),
+    ("COMPLETED", 800),
+    ("TERMINATING", 825),
+    ("TERMINATED", 850),
+    ("DEPROVISIONING", 900),
+    ("DEPROVISIONING_REQUESTED", 950),
+    ("DEPROVISIONED", 1000),
+];
+
 impl TransferProcessEntry {
     pub fn new(transfer_process: TransferProcess) -> Self {
         Self(transfer_process)
     }
+
+    /// Pull transfers never complete on their own, reaching `STARTED` is the end of the flow.
+    pub fn follow_state(&self) -> FollowState {
+        let pull = self.0.transfer_type().to_uppercase().ends_with("PULL");
+        let terminal = match self.0.state() {
+            TransferProcessState::Completed | TransferProcessState::Terminated => true,
+            TransferProcessState::Started => pull,
+            _ => false,
+        };
+        FollowState::new(
+            self.0.id(),
+            self.state_name(),
+            self.state_timestamp(),
+            terminal,
+        )
+    }
+
+    fn state_name(&self) -> String {
+        match self.0.state() {
+            TransferProcessState::Other(other) => other.clone(),
+            state => screaming_snake_case(&format!("{:?}", state)),
+        }
+    }
 }
 
 pub type TransferProcessMsg = ResourcesMsg<TransferProcessEntry, TransferProcessEntry>;
@@ -22,9 +68,9 @@ pub type TransferProcessesComponent =
     ResourcesComponent<TransferProcessEntry, TransferProcessEntry>;
 
 impl TableEntry for TransferProcessEntry {
-    fn row(&self) -> Row<'_> {
+    fn cells(&self) -> Vec<String> {
         let private_properties = serde_json::to_string(self.0.private_properties()).unwrap();
-        Row::new(vec![
+        vec![
             self.0.id().to_string(),
             format!("{:?}", self.0.kind()),
             format!("{:?}", self.0.state()),
@@ -32,11 +78,11 @@ impl TableEntry for TransferProcessEntry {
             self.0.asset_id().to_string(),
             self.0.contract_id().to_string(),
             private_properties,
-        ])
+        ]
     }
 
-    fn headers() -> Row<'static> {
-        Row::new(vec![
+    fn columns() -> Vec<&'static str> {
+        vec![
             "ID",
             "TYPE",
             "STATE",
@@ -44,7 +90,7 @@ impl TableEntry for TransferProcessEntry {
             "ASSET_ID",
             "CONTRACT_AGREEMENT_ID",
             "PRIVATE_PROPERTIES",
-        ])
+        ]
     }
 }
 
@@ -83,4 +129,16 @@ impl DrawableResource for TransferProcessEntry {
             ),
         ]
     }
+
+    fn state_codes() -> StateCodes {
+        STATE_CODES
+    }
+
+    fn state(&self) -> Option<String> {
+        Some(self.state_name())
+    }
+
+    fn state_timestamp(&self) -> Option<i64> {
+        Some(self.0.state_timestamp())
+    }
 }
@@ -1,12 +1,19 @@
 use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
     fs::File,
     io::{BufReader, Read},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    time::Duration,
 };
 
 use edc_connector_client::EdcConnectorApiVersion;
+use reqwest::Url;
 use serde::Deserialize;
 
+pub mod edit;
+pub mod watch;
+
 pub fn get_app_config_path() -> anyhow::Result<std::path::PathBuf> {
     let mut path = if cfg!(target_os = "macos") {
         dirs_next::home_dir().map(|h| h.join(".config"))
@@ -20,42 +27,198 @@ pub fn get_app_config_path() -> anyhow::Result<std::path::PathBuf> {
     Ok(path)
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct Config {
     pub connectors: Vec<ConnectorConfig>,
+    #[serde(default)]
+    pub persist_state_history: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConfigError {
+    Io(String),
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    Invalid(Vec<String>),
+}
+
+impl ConfigError {
+    fn parse(err: toml::de::Error, contents: &str) -> ConfigError {
+        let position = err.span().map(|span| {
+            let before = &contents[..span.start.min(contents.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+            (line, column)
+        });
+        ConfigError::Parse {
+            message: err.message().to_string(),
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+        }
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        match self {
+            ConfigError::Io(msg) => vec![msg.clone()],
+            ConfigError::Parse {
+                message,
+                line: Some(line),
+                column: Some(column),
+            } => vec![format!("line {}, column {}: {}", line, column, message)],
+            ConfigError::Parse { message, .. } => vec![message.clone()],
+            ConfigError::Invalid(errors) => errors.clone(),
+        }
+    }
 }
 
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.messages().join("; "))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
 impl Config {
-    pub fn parse(path: &PathBuf) -> anyhow::Result<Config> {
-        let file = File::open(path)?;
-        let mut buf_reader = BufReader::new(file);
+    pub fn parse(path: &Path) -> Result<Config, ConfigError> {
         let mut contents = String::new();
-        buf_reader.read_to_string(&mut contents)?;
+        File::open(path)
+            .map(BufReader::new)
+            .and_then(|mut reader| reader.read_to_string(&mut contents))
+            .map_err(|err| ConfigError::Io(format!("{}: {}", path.display(), err)))?;
+
+        let config: Config =
+            toml::from_str(&contents).map_err(|err| ConfigError::parse(err, &contents))?;
 
-        let config: Result<Config, toml::de::Error> = toml::from_str(&contents);
-        match config {
-            Ok(config) => Ok(config),
-            Err(e) => panic!("fail to parse config file: {}", e),
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = vec![];
+        let mut names = HashSet::new();
+
+        for (idx, connector) in self.connectors.iter().enumerate() {
+            let prefix = format!("connectors[{}] ({})", idx, connector.name);
+
+            if !connector.name.trim().is_empty() && !names.insert(connector.name.as_str()) {
+                errors.push(format!("{}: duplicate connector name", prefix));
+            }
+
+            errors.extend(
+                connector
+                    .validate()
+                    .into_iter()
+                    .map(|err| format!("{}: {}", prefix, err)),
+            );
         }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(errors))
+        }
+    }
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    match Url::parse(url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+        Ok(url) => Err(format!("unsupported scheme {}", url.scheme())),
+        Err(err) => Err(err.to_string()),
     }
 }
 
+fn is_header_name(name: &str) -> bool {
+    reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_ok()
+}
+
 pub fn default_file() -> anyhow::Result<PathBuf> {
     Ok(get_app_config_path()?.join("config.toml"))
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct ConnectorConfig {
     name: String,
     address: String,
     #[serde(default)]
-    api_version: ConnectorApiVersion,
+    api_version: Option<ConnectorApiVersion>,
     auth: AuthKind,
     #[serde(default)]
+    secret: SecretSource,
+    #[serde(default)]
     participant_context_id: Option<String>,
+    /// Static headers sent with every request, e.g. for a gateway in front of the connector.
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    transport: TransportConfig,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+/// HTTP settings for connectors behind proxies, private CAs or flaky gateways.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TransportConfig {
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Extra PEM encoded CA certificates trusted in addition to the system ones.
+    #[serde(default)]
+    pub ca_certs: Vec<PathBuf>,
+    /// PEM encoded client certificate for mTLS, with its PKCS#8 key in `client_key`.
+    #[serde(default)]
+    pub client_cert: Option<PathBuf>,
+    #[serde(default)]
+    pub client_key: Option<PathBuf>,
+    /// Skips the verification of server certificates, only meant for development.
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// How many times failed GET requests are retried.
+    #[serde(default)]
+    pub retries: u32,
+    /// Delay before the first retry, doubled on each following one.
+    #[serde(default)]
+    pub retry_backoff_ms: Option<u64>,
+}
+
+impl TransportConfig {
+    pub fn is_default(&self) -> bool {
+        self == &TransportConfig::default()
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_secs.map(Duration::from_secs)
+    }
+
+    pub fn retry_backoff(&self) -> Duration {
+        Duration::from_millis(self.retry_backoff_ms.unwrap_or(200))
+    }
+
+    fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if let Some(Err(err)) = self.proxy.as_deref().map(validate_url) {
+            errors.push(format!("invalid proxy: {}", err));
+        }
+
+        if self.client_cert.is_some() != self.client_key.is_some() {
+            errors.push(String::from(
+                "client_cert and client_key must be set together",
+            ));
+        }
+
+        if self.timeout_secs == Some(0) {
+            errors.push(String::from("timeout_secs must be greater than 0"));
+        }
+
+        errors
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ConnectorApiVersion {
     #[default]
@@ -63,29 +226,161 @@ pub enum ConnectorApiVersion {
     V4,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(tag = "type")]
 #[serde(rename_all = "kebab-case")]
 pub enum AuthKind {
     #[default]
     NoAuth,
     Token {
+        #[serde(default)]
         token_alias: String,
     },
     #[serde(rename = "oauth2")]
     OAuth {
         client_id: String,
         token_url: String,
+        #[serde(default)]
         secret_alias: String,
+        #[serde(flatten)]
+        options: OAuthOptions,
+    },
+    Basic {
+        username: String,
+        #[serde(default)]
+        password_alias: String,
+    },
+    /// The secret is sent as the value of an arbitrary header.
+    Header {
+        header: String,
+        #[serde(default)]
+        token_alias: String,
     },
 }
 
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct OAuthOptions {
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+    #[serde(default)]
+    pub audience: Option<String>,
+    #[serde(default)]
+    pub client_auth: OAuthClientAuth,
+    /// The `kid` of the signed client assertion.
+    #[serde(default)]
+    pub key_id: Option<String>,
+}
+
+/// How the client authenticates against the token endpoint.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthClientAuth {
+    /// The secret is the client secret, sent with basic auth.
+    #[default]
+    ClientSecret,
+    /// The secret is a PEM private key used to sign a client assertion.
+    PrivateKeyJwt,
+}
+
+impl OAuthClientAuth {
+    pub fn as_str(&self) -> &str {
+        match self {
+            OAuthClientAuth::ClientSecret => "client_secret",
+            OAuthClientAuth::PrivateKeyJwt => "private_key_jwt",
+        }
+    }
+}
+
+/// Where the token or client secret of a connector comes from.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag = "source")]
+#[serde(rename_all = "kebab-case")]
+pub enum SecretSource {
+    /// The OS keyring entry named by the alias of the auth.
+    #[default]
+    Keyring,
+    Env {
+        var: String,
+    },
+    File {
+        path: PathBuf,
+    },
+    /// The trimmed stdout of a shell command, e.g. `pass show edc/token`.
+    Command {
+        command: String,
+    },
+}
+
+impl SecretSource {
+    pub fn name(&self) -> &str {
+        match self {
+            SecretSource::Keyring => "keyring",
+            SecretSource::Env { .. } => "env",
+            SecretSource::File { .. } => "file",
+            SecretSource::Command { .. } => "command",
+        }
+    }
+
+    pub fn is_keyring(&self) -> bool {
+        matches!(self, SecretSource::Keyring)
+    }
+}
+
 impl AuthKind {
     pub fn kind(&self) -> &str {
         match self {
             AuthKind::NoAuth => "No auth",
             AuthKind::Token { .. } => "Token based",
             AuthKind::OAuth { .. } => "OAuth2",
+            AuthKind::Basic { .. } => "Basic auth",
+            AuthKind::Header { .. } => "Header",
+        }
+    }
+
+    /// The name of the auth type as written in the config file.
+    pub fn type_name(&self) -> &str {
+        match self {
+            AuthKind::NoAuth => "no-auth",
+            AuthKind::Token { .. } => "token",
+            AuthKind::OAuth { .. } => "oauth2",
+            AuthKind::Basic { .. } => "basic",
+            AuthKind::Header { .. } => "header",
+        }
+    }
+
+    pub fn secret_alias(&self) -> Option<&str> {
+        match self {
+            AuthKind::NoAuth => None,
+            AuthKind::Token { token_alias } => Some(token_alias),
+            AuthKind::OAuth { secret_alias, .. } => Some(secret_alias),
+            AuthKind::Basic { password_alias, .. } => Some(password_alias),
+            AuthKind::Header { token_alias, .. } => Some(token_alias),
+        }
+    }
+
+    /// Human readable description of how requests are authenticated.
+    pub fn describe(&self) -> String {
+        match self {
+            AuthKind::NoAuth => String::from("none"),
+            AuthKind::Token { .. } => String::from("X-Api-Key header"),
+            AuthKind::OAuth {
+                client_id, options, ..
+            } => {
+                let mut desc = format!(
+                    "OAuth2 client credentials ({}, client {})",
+                    options.client_auth.as_str(),
+                    client_id
+                );
+                if let Some(scopes) = &options.scopes {
+                    desc.push_str(&format!(", scopes {}", scopes.join(" ")));
+                }
+                if let Some(audience) = &options.audience {
+                    desc.push_str(&format!(", audience {}", audience));
+                }
+                desc
+            }
+            AuthKind::Basic { username, .. } => format!("Basic auth as {}", username),
+            AuthKind::Header { header, .. } => format!("{} header", header),
         }
     }
 }
@@ -96,11 +391,104 @@ impl ConnectorConfig {
             name,
             address,
             auth,
-            api_version: ConnectorApiVersion::V3,
+            api_version: None,
+            secret: SecretSource::default(),
             participant_context_id: None,
+            headers: BTreeMap::new(),
+            transport: TransportConfig::default(),
+        }
+    }
+
+    /// Keeps the settings of `previous` that can only be edited in the config file.
+    pub fn with_settings_of(self, previous: &ConnectorConfig) -> Self {
+        let auth = match (self.auth, &previous.auth) {
+            (
+                AuthKind::OAuth {
+                    client_id,
+                    token_url,
+                    secret_alias,
+                    ..
+                },
+                AuthKind::OAuth { options, .. },
+            ) => AuthKind::OAuth {
+                client_id,
+                token_url,
+                secret_alias,
+                options: options.clone(),
+            },
+            (auth, _) => auth,
+        };
+        Self {
+            auth,
+            secret: previous.secret.clone(),
+            headers: previous.headers.clone(),
+            transport: previous.transport.clone(),
+            ..self
         }
     }
 
+    pub fn with_api_version(mut self, api_version: Option<ConnectorApiVersion>) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    pub fn with_participant_context_id(mut self, participant_context_id: Option<String>) -> Self {
+        self.participant_context_id = participant_context_id;
+        self
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if self.name.trim().is_empty() {
+            errors.push(String::from("name must not be empty"));
+        }
+
+        if let Err(err) = validate_url(&self.address) {
+            errors.push(format!("invalid address: {}", err));
+        }
+
+        match &self.auth {
+            AuthKind::OAuth { token_url, .. } => {
+                if let Err(err) = validate_url(token_url) {
+                    errors.push(format!("invalid token_url: {}", err));
+                }
+            }
+            AuthKind::Basic { username, .. } if username.trim().is_empty() => {
+                errors.push(String::from("username must not be empty"))
+            }
+            AuthKind::Header { header, .. } if !is_header_name(header) => {
+                errors.push(format!("invalid header name {:?}", header))
+            }
+            _ => {}
+        }
+
+        errors.extend(self.transport.validate());
+
+        for (name, value) in &self.headers {
+            if !is_header_name(name) {
+                errors.push(format!("invalid header name {:?}", name));
+            } else if reqwest::header::HeaderValue::from_str(value).is_err() {
+                errors.push(format!("invalid value for header {}", name));
+            }
+        }
+
+        match (&self.secret, self.auth.secret_alias()) {
+            (SecretSource::Keyring, Some(alias)) if alias.trim().is_empty() => {
+                errors.push(String::from("a keyring alias is required"))
+            }
+            (SecretSource::Env { var }, Some(_)) if var.trim().is_empty() => {
+                errors.push(String::from("secret var must not be empty"))
+            }
+            (SecretSource::Command { command }, Some(_)) if command.trim().is_empty() => {
+                errors.push(String::from("secret command must not be empty"))
+            }
+            _ => {}
+        }
+
+        errors
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -113,8 +501,20 @@ impl ConnectorConfig {
         &self.auth
     }
 
-    pub fn version(&self) -> &ConnectorApiVersion {
-        &self.api_version
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.headers
+    }
+
+    pub fn transport(&self) -> &TransportConfig {
+        &self.transport
+    }
+
+    pub fn secret_source(&self) -> &SecretSource {
+        &self.secret
+    }
+
+    pub fn version(&self) -> Option<&ConnectorApiVersion> {
+        self.api_version.as_ref()
     }
 
     pub fn participant_context_id(&self) -> Option<&String> {
@@ -122,11 +522,28 @@ impl ConnectorConfig {
     }
 }
 
-impl From<ConnectorApiVersion> for EdcConnectorApiVersion {
-    fn from(version: ConnectorApiVersion) -> Self {
-        match version {
+impl ConnectorApiVersion {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ConnectorApiVersion::V3 => "v3",
+            ConnectorApiVersion::V4 => "v4",
+        }
+    }
+
+    /// The version as used in management API paths, where V4 is still served as `v4beta`.
+    pub fn api_version(&self) -> EdcConnectorApiVersion {
+        match self {
             ConnectorApiVersion::V3 => EdcConnectorApiVersion::V3,
             ConnectorApiVersion::V4 => EdcConnectorApiVersion::V4,
         }
     }
+
+    pub fn parse(version: &str) -> anyhow::Result<Option<ConnectorApiVersion>> {
+        match version.trim().to_lowercase().as_str() {
+            "" | "auto" => Ok(None),
+            "v3" => Ok(Some(ConnectorApiVersion::V3)),
+            "v4" => Ok(Some(ConnectorApiVersion::V4)),
+            other => anyhow::bail!("Unknown API version {}, expected v3 or v4", other),
+        }
+    }
 }
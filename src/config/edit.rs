@@ -0,0 +1,310 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, Value};
+
+use super::{watch, AuthKind, ConnectorConfig, OAuthClientAuth, SecretSource, TransportConfig};
+
+/// Adds or replaces the connector named `previous` (or `connector.name` when adding),
+/// keeping the formatting and comments of the rest of the file.
+pub fn save_connector(
+    path: &Path,
+    previous: Option<&str>,
+    connector: &ConnectorConfig,
+) -> anyhow::Result<()> {
+    let mut doc = read(path)?;
+    let connectors = connectors_mut(&mut doc)?;
+    let name = previous.unwrap_or(&connector.name);
+
+    let existing = connectors
+        .iter()
+        .position(|table| table.get("name").and_then(Item::as_str) == Some(name));
+
+    match existing.and_then(|idx| connectors.get_mut(idx)) {
+        Some(table) => write_connector(table, connector),
+        None if previous.is_some() => anyhow::bail!("Connector {} not found in config", name),
+        None => {
+            let mut table = Table::new();
+            write_connector(&mut table, connector);
+            connectors.push(table);
+        }
+    }
+
+    write(path, doc)
+}
+
+pub fn remove_connector(path: &Path, name: &str) -> anyhow::Result<()> {
+    let mut doc = read(path)?;
+    let connectors = connectors_mut(&mut doc)?;
+    let before = connectors.len();
+    connectors.retain(|table| table.get("name").and_then(Item::as_str) != Some(name));
+
+    if connectors.len() == before {
+        anyhow::bail!("Connector {} not found in config", name)
+    }
+
+    write(path, doc)
+}
+
+fn write(path: &Path, doc: DocumentMut) -> anyhow::Result<()> {
+    let contents = doc.to_string();
+    watch::record_write(path, &contents);
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+fn read(path: &Path) -> anyhow::Result<DocumentMut> {
+    let contents = if path.exists() {
+        fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    Ok(contents.parse()?)
+}
+
+fn connectors_mut(doc: &mut DocumentMut) -> anyhow::Result<&mut ArrayOfTables> {
+    doc.entry("connectors")
+        .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or_else(|| anyhow::anyhow!("connectors must be an array of tables"))
+}
+
+fn write_connector(table: &mut Table, connector: &ConnectorConfig) {
+    set(table, "name", &connector.name);
+    set(table, "address", &connector.address);
+    set_optional(
+        table,
+        "api_version",
+        connector.api_version.as_ref().map(|v| v.as_str()),
+    );
+    set_table(table, "auth", Some(auth_table(&connector.auth)));
+    set_table(table, "secret", secret_table(&connector.secret));
+    set_optional(
+        table,
+        "participant_context_id",
+        connector.participant_context_id.as_deref(),
+    );
+    set_table(table, "headers", headers_table(&connector.headers));
+    set_table(table, "transport", transport_table(&connector.transport));
+}
+
+/// Replaces a value while keeping the comments and whitespace around the old one.
+fn set(table: &mut Table, key: &str, val: impl Into<Value>) {
+    let mut val = val.into();
+    if let Some(old) = table.get(key).and_then(Item::as_value) {
+        *val.decor_mut() = old.decor().clone();
+    }
+    table[key] = Item::Value(val);
+}
+
+/// Updates the keys of an inline or standard table in place, keeping its style and the comments
+/// around its values, or removes it when `None`.
+fn set_table(table: &mut Table, key: &str, val: Option<InlineTable>) {
+    let Some(val) = val else {
+        table.remove(key);
+        return;
+    };
+    let Some(existing) = table.get_mut(key).and_then(Item::as_table_like_mut) else {
+        set(table, key, val);
+        return;
+    };
+
+    let stale = existing
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !val.contains_key(key))
+        .collect::<Vec<_>>();
+    for key in stale {
+        existing.remove(&key);
+    }
+    for (key, val) in val.iter() {
+        match existing.get_mut(key) {
+            Some(Item::Value(old)) => {
+                let decor = old.decor().clone();
+                *old = val.clone();
+                *old.decor_mut() = decor;
+            }
+            _ => {
+                existing.insert(key, Item::Value(val.clone()));
+            }
+        }
+    }
+}
+
+fn set_optional(table: &mut Table, key: &str, val: Option<&str>) {
+    match val {
+        Some(val) => set(table, key, val),
+        None => {
+            table.remove(key);
+        }
+    }
+}
+
+fn secret_table(secret: &SecretSource) -> Option<InlineTable> {
+    let mut table = InlineTable::new();
+    table.insert("source", secret.name().into());
+    match secret {
+        SecretSource::Keyring => return None,
+        SecretSource::Env { var } => {
+            table.insert("var", var.as_str().into());
+        }
+        SecretSource::File { path } => {
+            table.insert("path", path.to_string_lossy().as_ref().into());
+        }
+        SecretSource::Command { command } => {
+            table.insert("command", command.as_str().into());
+        }
+    }
+    Some(table)
+}
+
+fn transport_table(transport: &TransportConfig) -> Option<InlineTable> {
+    if transport.is_default() {
+        return None;
+    }
+    let path = |path: &PathBuf| Value::from(path.to_string_lossy().as_ref());
+    let mut table = InlineTable::new();
+    if let Some(proxy) = &transport.proxy {
+        table.insert("proxy", proxy.as_str().into());
+    }
+    if !transport.ca_certs.is_empty() {
+        table.insert(
+            "ca_certs",
+            transport
+                .ca_certs
+                .iter()
+                .map(path)
+                .collect::<Array>()
+                .into(),
+        );
+    }
+    if let Some(cert) = &transport.client_cert {
+        table.insert("client_cert", path(cert));
+    }
+    if let Some(key) = &transport.client_key {
+        table.insert("client_key", path(key));
+    }
+    if transport.insecure_skip_verify {
+        table.insert("insecure_skip_verify", true.into());
+    }
+    if let Some(timeout) = transport.timeout_secs {
+        table.insert("timeout_secs", (timeout as i64).into());
+    }
+    if transport.retries > 0 {
+        table.insert("retries", i64::from(transport.retries).into());
+    }
+    if let Some(backoff) = transport.retry_backoff_ms {
+        table.insert("retry_backoff_ms", (backoff as i64).into());
+    }
+    Some(table)
+}
+
+fn auth_table(auth: &AuthKind) -> InlineTable {
+    let mut table = InlineTable::new();
+    table.insert("type", auth.type_name().into());
+    match auth {
+        AuthKind::NoAuth => {}
+        AuthKind::Token { token_alias } => insert_alias(&mut table, "token_alias", token_alias),
+        AuthKind::OAuth {
+            client_id,
+            token_url,
+            secret_alias,
+            options,
+        } => {
+            table.insert("client_id", client_id.as_str().into());
+            table.insert("token_url", token_url.as_str().into());
+            insert_alias(&mut table, "secret_alias", secret_alias);
+            if let Some(scopes) = &options.scopes {
+                table.insert("scopes", scopes.iter().collect::<Array>().into());
+            }
+            if let Some(audience) = &options.audience {
+                table.insert("audience", audience.as_str().into());
+            }
+            if options.client_auth != OAuthClientAuth::default() {
+                table.insert("client_auth", options.client_auth.as_str().into());
+            }
+            if let Some(key_id) = &options.key_id {
+                table.insert("key_id", key_id.as_str().into());
+            }
+        }
+        AuthKind::Basic {
+            username,
+            password_alias,
+        } => {
+            table.insert("username", username.as_str().into());
+            insert_alias(&mut table, "password_alias", password_alias);
+        }
+        AuthKind::Header {
+            header,
+            token_alias,
+        } => {
+            table.insert("header", header.as_str().into());
+            insert_alias(&mut table, "token_alias", token_alias);
+        }
+    }
+    table
+}
+
+fn insert_alias(table: &mut InlineTable, key: &str, alias: &str) {
+    if !alias.is_empty() {
+        table.insert(key, alias.into());
+    }
+}
+
+fn headers_table(headers: &BTreeMap<String, String>) -> Option<InlineTable> {
+    if headers.is_empty() {
+        return None;
+    }
+    Some(
+        headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), Value::from(value.as_str())))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+
+    use super::*;
+
+    const CONFIG: &str = r#"# connectors
+[[connectors]]
+name = "provider"
+address = "http://localhost:29193/management"
+
+[connectors.auth]
+# the token is in the keyring
+type = "token"
+token_alias = "provider" # alias
+"#;
+
+    fn rewrite(config: &str) -> String {
+        let connector = toml::from_str::<Config>(config)
+            .unwrap()
+            .connectors
+            .remove(0);
+        let mut doc = config.parse::<DocumentMut>().unwrap();
+        let table = connectors_mut(&mut doc).unwrap().get_mut(0).unwrap();
+        write_connector(table, &connector);
+        doc.to_string()
+    }
+
+    #[test]
+    fn keeps_auth_comments() {
+        assert_eq!(rewrite(CONFIG), CONFIG);
+    }
+
+    #[test]
+    fn updates_auth_keys_in_place() {
+        let config = CONFIG.replace(
+            "token_alias = \"provider\" # alias",
+            "token_alias = \"provider\" # alias\nunknown = 1",
+        );
+        assert_eq!(rewrite(&config), CONFIG);
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::{
+    mpsc::{unbounded_channel, UnboundedReceiver},
+    Mutex,
+};
+
+use super::{Config, ConfigError};
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+lazy_static! {
+    /// The contents the app last wrote to each config file, which need no reload.
+    static ref WRITTEN: std::sync::Mutex<HashMap<PathBuf, String>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+/// Records a write of the app, to be called before writing the file.
+pub fn record_write(path: &Path, contents: &str) {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut written = WRITTEN.lock().unwrap_or_else(|err| err.into_inner());
+    written.insert(path, contents.to_string());
+}
+
+fn written_by_app(path: &Path) -> bool {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return false;
+    };
+    let written = WRITTEN.lock().unwrap_or_else(|err| err.into_inner());
+    written.get(path) == Some(&contents)
+}
+
+pub struct ConfigWatcher {
+    path: PathBuf,
+    changes: Mutex<UnboundedReceiver<()>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: &Path) -> anyhow::Result<Arc<ConfigWatcher>> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let file_name = path.file_name().map(ToOwned::to_owned);
+        let (tx, rx) = unbounded_channel();
+
+        // Editors often replace the file instead of writing it in place, so the parent
+        // directory is watched and events are filtered by file name.
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let matches = event
+                    .paths
+                    .iter()
+                    .any(|p| p.file_name().map(ToOwned::to_owned) == file_name);
+                if matches && !event.kind.is_access() {
+                    let _ = tx.send(());
+                }
+            }
+        })?;
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Arc::new(ConfigWatcher {
+            path,
+            changes: Mutex::new(rx),
+            _watcher: watcher,
+        }))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub async fn changed(self: Arc<Self>) -> Result<Config, ConfigError> {
+        let mut changes = self.changes.lock().await;
+        loop {
+            if changes.recv().await.is_none() {
+                return std::future::pending().await;
+            }
+            tokio::time::sleep(DEBOUNCE).await;
+            while changes.try_recv().is_ok() {}
+
+            // Saves from the connectors view already reloaded the connectors.
+            if !written_by_app(&self.path) {
+                return Config::parse(&self.path);
+            }
+        }
+    }
+}
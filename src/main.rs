@@ -1,12 +1,20 @@
 use app::App;
 use clap::{Parser, Subcommand};
-use config::{default_file, Config, ConnectorConfig};
-use edc_connector_client::{Auth, EdcConnectorClient};
+use cli::{
+    ApplyArgs, BackupArgs, QueryArgs, ResourceKind, RestoreArgs, Target, WaitArgs, WaitResource,
+};
+use client::ClientAuth;
+use config::{default_file, ConnectorConfig};
 use logging::initialize_logging;
 use runner::Runner;
 use std::{path::PathBuf, time::Duration};
-use types::connector::{Connector, ConnectorStatus};
+use types::{
+    api_error::ApiError,
+    connector::{Connector, ConnectorStatus},
+};
 mod app;
+mod cli;
+mod client;
 mod components;
 mod config;
 mod logging;
@@ -18,6 +26,49 @@ mod widgets;
 async fn main() -> anyhow::Result<()> {
     initialize_logging()?;
     let cli = Cli::parse();
+
+    match cli.mode {
+        Some(Commands::List {
+            resource,
+            target,
+            query,
+        }) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            exit_on_error(cli::list(&config, resource, target, query).await.map(|_| 0));
+        }
+        Some(Commands::Get {
+            resource,
+            id,
+            data_address,
+            target,
+        }) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            exit_on_error(
+                cli::get(&config, resource, &id, data_address, target)
+                    .await
+                    .map(|_| 0),
+            );
+        }
+        Some(Commands::Apply(args)) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            exit_on_error(cli::apply(&config, args).await.map(|_| 0));
+        }
+        Some(Commands::Backup(args)) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            exit_on_error(cli::backup(&config, args).await.map(|_| 0));
+        }
+        Some(Commands::Restore(args)) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            exit_on_error(cli::restore(&config, args).await.map(|_| 0));
+        }
+        Some(Commands::Wait { resource, id, args }) => {
+            let config = cli.config.map(Ok).unwrap_or_else(default_file)?;
+            let outcome = cli::wait(&config, resource, &id, args).await;
+            exit_on_error(outcome.map(|outcome| outcome.exit_code()));
+        }
+        _ => {}
+    }
+
     tui::install_panic_hook();
     let terminal = tui::init_terminal()?;
 
@@ -25,10 +76,7 @@ async fn main() -> anyhow::Result<()> {
         Some(Commands::Connector { url, name, token }) => {
             init_app_single_connector(url, name, token).await
         }
-        None => {
-            let config = Config::parse(&cli.config.map(Ok).unwrap_or_else(default_file)?)?;
-            App::init(config)
-        }
+        _ => App::init(cli.config.map(Ok).unwrap_or_else(default_file)?).await,
     };
     let mut runner = Runner::new(Duration::from_millis(250), app);
     runner.run(terminal).await?;
@@ -36,17 +84,25 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Headless commands report failures like the footer does, without a backtrace.
+fn exit_on_error(result: anyhow::Result<i32>) -> ! {
+    match result {
+        Ok(code) => std::process::exit(code),
+        Err(err) => {
+            eprintln!("Error: {}", ApiError::new(&err).summary());
+            std::process::exit(1)
+        }
+    }
+}
+
 async fn init_app_single_connector(
     url: String,
     name: Option<String>,
     token: Option<String>,
 ) -> App {
-    let auth = token.map(Auth::api_token).unwrap_or_else(|| Auth::NoAuth);
-    let client = EdcConnectorClient::builder()
-        .management_url(url.clone())
-        .with_auth(auth)
-        .build()
-        .unwrap();
+    let auth = token
+        .map(ClientAuth::ApiToken)
+        .unwrap_or(ClientAuth::NoAuth);
 
     let cfg = ConnectorConfig::new(
         name.unwrap_or_else(|| url.clone()),
@@ -56,9 +112,9 @@ async fn init_app_single_connector(
         },
     );
 
-    let connector = Connector::new(cfg, client, ConnectorStatus::Connected);
+    let connector = Connector::new(cfg, reqwest::Client::new(), auth, ConnectorStatus::Unknown);
 
-    App::init_with_connectors(vec![connector])
+    App::init_with_connectors(vec![connector], false, None)
 }
 
 mod tui {
@@ -114,4 +170,39 @@ pub enum Commands {
         #[arg(short, long)]
         token: Option<String>,
     },
+    /// Print the resources of a configured connector without starting the TUI
+    List {
+        #[arg(value_enum)]
+        resource: ResourceKind,
+        #[command(flatten)]
+        target: Target,
+        #[command(flatten)]
+        query: QueryArgs,
+    },
+    /// Print a single resource of a configured connector without starting the TUI
+    Get {
+        #[arg(value_enum)]
+        resource: ResourceKind,
+        id: String,
+        /// Print the data address of an EDR instead of its entry
+        #[arg(long)]
+        data_address: bool,
+        #[command(flatten)]
+        target: Target,
+    },
+    /// Create, update or delete assets, policies and contract definitions to match manifests
+    Apply(ApplyArgs),
+    /// Save the assets, policies and contract definitions of a connector
+    Backup(BackupArgs),
+    /// Recreate the resources of a backup on a connector, reporting conflicts
+    Restore(RestoreArgs),
+    /// Block until a transfer process or contract negotiation reaches one of the given states.
+    /// Exits with 0 when reached, 2 on failure, 3 on timeout, 4 when ended in another state and 1 on errors
+    Wait {
+        #[arg(value_enum)]
+        resource: WaitResource,
+        id: String,
+        #[command(flatten)]
+        args: WaitArgs,
+    },
 }
@@ -1,6 +1,7 @@
 use std::{collections::VecDeque, sync::Arc, time::Duration};
 
 use crossterm::event;
+use futures::future::BoxFuture;
 use ratatui::{backend::Backend, Terminal};
 use tokio::sync::Mutex;
 
@@ -13,7 +14,10 @@ pub struct Runner<C: Component> {
     component: C,
 }
 
-impl<C: Component + ActionHandler<Msg = <C as Component>::Msg> + Send> Runner<C> {
+type AsyncMsgs<M> = Arc<Mutex<VecDeque<ComponentMsg<M>>>>;
+type Cmd<M> = BoxFuture<'static, anyhow::Result<Vec<ComponentMsg<M>>>>;
+
+impl<C: Component<Props = ()> + ActionHandler<Msg = <C as Component>::Msg> + Send> Runner<C> {
     pub fn new(tick_rate: Duration, component: C) -> Self {
         Self {
             tick_rate,
@@ -25,9 +29,18 @@ impl<C: Component + ActionHandler<Msg = <C as Component>::Msg> + Send> Runner<C>
         terminal.clear()?;
 
         let mut should_quit = false;
-        let async_msgs = Arc::new(Mutex::new(
-            VecDeque::<ComponentMsg<<C as Component>::Msg>>::new(),
-        ));
+        let async_msgs: AsyncMsgs<<C as Component>::Msg> = Arc::new(Mutex::new(VecDeque::new()));
+
+        let init = self.component.init(()).await?;
+        Self::spawn_cmds(init.cmds, &async_msgs);
+        async_msgs.lock().await.extend(init.msgs);
+        for a in init.actions {
+            async_msgs
+                .lock()
+                .await
+                .extend(self.component.handle_action(a)?);
+        }
+
         loop {
             if should_quit {
                 break;
@@ -63,15 +76,7 @@ impl<C: Component + ActionHandler<Msg = <C as Component>::Msg> + Send> Runner<C>
                                 msgs.push_back(m);
                             }
 
-                            for c in ret.cmds {
-                                let inner_async_msg = async_msgs.clone();
-                                tokio::task::spawn(async move {
-                                    for m in c.await.unwrap() {
-                                        let mut msg_guard = inner_async_msg.lock().await;
-                                        msg_guard.push_back(m);
-                                    }
-                                });
-                            }
+                            Self::spawn_cmds(ret.cmds, &async_msgs);
 
                             ret.actions
                         }
@@ -92,4 +97,19 @@ impl<C: Component + ActionHandler<Msg = <C as Component>::Msg> + Send> Runner<C>
 
         Ok(())
     }
+
+    fn spawn_cmds(
+        cmds: Vec<Cmd<<C as Component>::Msg>>,
+        async_msgs: &AsyncMsgs<<C as Component>::Msg>,
+    ) {
+        for c in cmds {
+            let inner_async_msg = async_msgs.clone();
+            tokio::task::spawn(async move {
+                for m in c.await.unwrap() {
+                    let mut msg_guard = inner_async_msg.lock().await;
+                    msg_guard.push_back(m);
+                }
+            });
+        }
+    }
 }
@@ -1,3 +1,10 @@
+pub mod api_error;
+pub mod compare;
 pub mod connector;
+pub mod copy;
+pub mod history;
 pub mod info;
+pub mod manifest;
 pub mod nav;
+pub mod participant;
+pub mod query;
@@ -0,0 +1,91 @@
+use edc_connector_client::{Error, ManagementApiErrorDetailKind};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A failed request, with the violations reported by the management API if any.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    status: Option<u16>,
+    message: String,
+    violations: Vec<Violation>,
+}
+
+/// One entry of the error array returned by the management API.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub path: Option<String>,
+    pub invalid_value: Option<Value>,
+}
+
+impl ApiError {
+    pub fn new(err: &anyhow::Error) -> ApiError {
+        let Some(Error::ManagementApi(api_err)) = err.downcast_ref::<Error>() else {
+            return ApiError {
+                status: None,
+                message: format!("{:#}", err),
+                violations: vec![],
+            };
+        };
+
+        let violations = match &api_err.error_detail {
+            ManagementApiErrorDetailKind::Parsed(details) => details
+                .iter()
+                .map(|detail| Violation {
+                    message: detail.message.clone(),
+                    kind: Some(detail.kind.clone()),
+                    path: None,
+                    invalid_value: None,
+                })
+                .collect(),
+            ManagementApiErrorDetailKind::Raw(body) => Self::parse_violations(body),
+        };
+
+        ApiError {
+            status: Some(api_err.status_code.as_u16()),
+            message: api_err.to_string(),
+            violations,
+        }
+    }
+
+    fn parse_violations(body: &str) -> Vec<Violation> {
+        if let Ok(violations) = serde_json::from_str::<Vec<Violation>>(body) {
+            return violations;
+        }
+        if let Ok(violation) = serde_json::from_str::<Violation>(body) {
+            return vec![violation];
+        }
+        match body.trim() {
+            "" => vec![],
+            body => vec![Violation {
+                message: body.to_string(),
+                kind: None,
+                path: None,
+                invalid_value: None,
+            }],
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// One line for the footer, e.g. `400: asset id is blank (+2 more)`.
+    pub fn summary(&self) -> String {
+        let mut summary = match (self.status, self.violations.first()) {
+            (Some(status), Some(first)) => format!("{}: {}", status, first.message),
+            _ => self.message.clone(),
+        };
+        if self.violations.len() > 1 {
+            summary.push_str(&format!(" (+{} more)", self.violations.len() - 1));
+        }
+        summary
+    }
+}
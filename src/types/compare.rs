@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::manifest::{changed_values, Change};
+
+/// How a resource of the right connector differs from the left one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareStatus {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompareEntry {
+    pub id: String,
+    pub status: CompareStatus,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+    pub changes: Vec<Change>,
+}
+
+/// Resources of two connectors matched by id, only the ones that differ are kept.
+#[derive(Debug, Clone, Default)]
+pub struct Comparison {
+    pub entries: Vec<CompareEntry>,
+    pub unchanged: usize,
+}
+
+impl CompareStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompareStatus::Added => "added",
+            CompareStatus::Removed => "removed",
+            CompareStatus::Changed => "changed",
+        }
+    }
+}
+
+impl Comparison {
+    pub fn new(left: Vec<(String, Value)>, right: Vec<(String, Value)>) -> Comparison {
+        let mut right_by_id = right.into_iter().collect::<HashMap<_, _>>();
+        let mut comparison = Comparison::default();
+
+        for (id, left) in left {
+            match right_by_id.remove(&id) {
+                None => comparison.entries.push(CompareEntry {
+                    id,
+                    status: CompareStatus::Removed,
+                    left: Some(left),
+                    right: None,
+                    changes: vec![],
+                }),
+                Some(right) => {
+                    let changes = changed_values(&left, &right, "");
+                    if changes.is_empty() {
+                        comparison.unchanged += 1;
+                    } else {
+                        comparison.entries.push(CompareEntry {
+                            id,
+                            status: CompareStatus::Changed,
+                            left: Some(left),
+                            right: Some(right),
+                            changes,
+                        });
+                    }
+                }
+            }
+        }
+
+        comparison
+            .entries
+            .extend(right_by_id.into_iter().map(|(id, right)| CompareEntry {
+                id,
+                status: CompareStatus::Added,
+                left: None,
+                right: Some(right),
+                changes: vec![],
+            }));
+        comparison.entries.sort_by(|a, b| a.id.cmp(&b.id));
+        comparison
+    }
+}
@@ -1,41 +1,214 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
-use edc_connector_client::EdcConnectorClient;
+use reqwest::Client;
 
-use crate::config::ConnectorConfig;
+use crate::{
+    client::{ClientAuth, ManagementClient},
+    config::{ConnectorApiVersion, ConnectorConfig},
+};
 
 #[derive(Clone)]
 pub struct Connector {
     config: ConnectorConfig,
-    client: EdcConnectorClient,
+    http: Client,
+    client: ManagementClient,
+    auth: ClientAuth,
+    version: Option<ConnectorApiVersion>,
+    participant_context: Option<String>,
     status: ConnectorStatus,
 }
 
+/// A connector entered in the TUI, with the secret to store under its alias.
+#[derive(Clone)]
+pub struct NewConnector {
+    pub config: ConnectorConfig,
+    pub secret: Option<String>,
+}
+
+impl Debug for NewConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewConnector")
+            .field("config", &self.config)
+            .field("secret", &self.secret.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// A token or client secret entered in the credential prompt.
+#[derive(Clone)]
+pub struct Credentials {
+    pub secret: String,
+    pub save: bool,
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("secret", &"***")
+            .field("save", &self.save)
+            .finish()
+    }
+}
+
+/// Whether the connector rejected the credentials used for a request.
+pub fn is_unauthorized(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<edc_connector_client::Error>(),
+        Some(edc_connector_client::Error::ManagementApi(err)) if err.status_code.as_u16() == 401
+    )
+}
+
+/// Whether the connector answered with a 4xx status, which retrying won't change.
+pub fn is_client_error(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<edc_connector_client::Error>(),
+        Some(edc_connector_client::Error::ManagementApi(err)) if err.status_code.is_client_error()
+    )
+}
+
 #[derive(Clone, Debug)]
 pub enum ConnectorStatus {
-    Connected,
+    Unknown,
+    Connected { latency: Duration },
+    Unauthorized { status: u16, latency: Duration },
+    Failed { status: u16, latency: Duration },
+    AuthFailed(String),
+    Unreachable(String),
+    MissingSecret(String),
     Custom(String),
 }
 
 impl ConnectorStatus {
-    pub fn as_str(&self) -> &str {
+    pub fn label(&self) -> String {
         match self {
-            ConnectorStatus::Connected => "connected",
-            ConnectorStatus::Custom(msg) => msg,
+            ConnectorStatus::Unknown => String::from("checking..."),
+            ConnectorStatus::Connected { latency } => {
+                format!("connected ({}ms)", latency.as_millis())
+            }
+            ConnectorStatus::Unauthorized { status, latency } => {
+                format!("unauthorized {} ({}ms)", status, latency.as_millis())
+            }
+            ConnectorStatus::Failed { status, latency } => {
+                format!("error {} ({}ms)", status, latency.as_millis())
+            }
+            ConnectorStatus::AuthFailed(msg) => format!("auth failed: {}", msg),
+            ConnectorStatus::Unreachable(msg) => format!("unreachable: {}", msg),
+            ConnectorStatus::MissingSecret(msg) => format!("missing secret: {}", msg),
+            ConnectorStatus::Custom(msg) => msg.clone(),
         }
     }
+
+    /// Connectors with a broken local setup (e.g. missing secret) are not worth probing.
+    pub fn is_probeable(&self) -> bool {
+        !matches!(
+            self,
+            ConnectorStatus::Custom(_) | ConnectorStatus::MissingSecret(_)
+        )
+    }
+
+    /// Whether entering credentials could bring the connector back.
+    pub fn needs_credentials(&self) -> bool {
+        matches!(
+            self,
+            ConnectorStatus::MissingSecret(_)
+                | ConnectorStatus::AuthFailed(_)
+                | ConnectorStatus::Unauthorized { status: 401, .. }
+        )
+    }
 }
 
 impl Connector {
     pub fn new(
         config: ConnectorConfig,
-        client: EdcConnectorClient,
+        http: Client,
+        auth: ClientAuth,
         status: ConnectorStatus,
     ) -> Self {
+        let participant_context = config.participant_context_id().cloned();
+        // Participant contexts only exist on V4.
+        let version = match participant_context {
+            Some(_) => Some(ConnectorApiVersion::V4),
+            None => config.version().cloned(),
+        };
+        let client = Self::build_client(
+            &http,
+            &config,
+            auth.clone(),
+            version.clone().unwrap_or_default(),
+            participant_context.as_ref(),
+        );
         Self {
             config,
+            http,
             client,
+            auth,
+            version,
+            participant_context,
+            status,
+        }
+    }
+
+    fn build_client(
+        http: &Client,
+        config: &ConnectorConfig,
+        auth: ClientAuth,
+        version: ConnectorApiVersion,
+        participant_context: Option<&String>,
+    ) -> ManagementClient {
+        ManagementClient::new(
+            http.clone(),
+            config,
+            auth,
+            version,
+            participant_context.cloned(),
+        )
+    }
+
+    pub fn with_version(&self, version: ConnectorApiVersion) -> Connector {
+        Connector {
+            client: Self::build_client(
+                &self.http,
+                &self.config,
+                self.auth.clone(),
+                version.clone(),
+                self.participant_context.as_ref(),
+            ),
+            version: Some(version),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_participant_context(&self, participant_context: Option<String>) -> Connector {
+        let version = match participant_context {
+            Some(_) => Some(ConnectorApiVersion::V4),
+            None => self.version.clone(),
+        };
+        Connector {
+            client: Self::build_client(
+                &self.http,
+                &self.config,
+                self.auth.clone(),
+                version.clone().unwrap_or_default(),
+                participant_context.as_ref(),
+            ),
+            version,
+            participant_context,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_auth(&self, auth: ClientAuth, status: ConnectorStatus) -> Connector {
+        Connector {
+            client: Self::build_client(
+                &self.http,
+                &self.config,
+                auth.clone(),
+                self.version.clone().unwrap_or_default(),
+                self.participant_context.as_ref(),
+            ),
+            auth,
             status,
+            ..self.clone()
         }
     }
 
@@ -43,13 +216,36 @@ impl Connector {
         &self.config
     }
 
-    pub fn client(&self) -> &EdcConnectorClient {
+    pub fn client(&self) -> &ManagementClient {
         &self.client
     }
 
+    /// The HTTP client built from the transport settings, shared with the token requests.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    pub fn participant_context(&self) -> Option<&str> {
+        self.participant_context.as_deref()
+    }
+
+    /// Whether participant contexts can be listed and switched, which requires a V4 connector.
+    pub fn supports_participant_contexts(&self) -> bool {
+        self.version == Some(ConnectorApiVersion::V4)
+    }
+
+    /// The API version in use, either configured or detected. `None` until detection succeeds.
+    pub fn version(&self) -> Option<&ConnectorApiVersion> {
+        self.version.as_ref()
+    }
+
     pub fn status(&self) -> &ConnectorStatus {
         &self.status
     }
+
+    pub fn with_status(self, status: ConnectorStatus) -> Connector {
+        Connector { status, ..self }
+    }
 }
 
 impl Debug for Connector {
@@ -0,0 +1,43 @@
+use super::{connector::Connector, manifest::ManifestKind};
+
+/// A resource to copy from `source` to another configured connector.
+#[derive(Debug, Clone)]
+pub struct CopyRequest {
+    pub source: Connector,
+    pub kind: ManifestKind,
+    pub id: String,
+}
+
+/// What to do with resources that already exist on the target with different values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictMode {
+    #[default]
+    Skip,
+    Overwrite,
+    Rename,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CopyOptions {
+    /// Also copy the policies and assets referenced by a contract definition.
+    pub dependencies: bool,
+    pub conflicts: ConflictMode,
+}
+
+impl ConflictMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictMode::Skip => "skip",
+            ConflictMode::Overwrite => "overwrite",
+            ConflictMode::Rename => "rename",
+        }
+    }
+
+    pub fn next(&self) -> ConflictMode {
+        match self {
+            ConflictMode::Skip => ConflictMode::Overwrite,
+            ConflictMode::Overwrite => ConflictMode::Rename,
+            ConflictMode::Rename => ConflictMode::Skip,
+        }
+    }
+}
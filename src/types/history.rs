@@ -0,0 +1,152 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::logging::get_data_dir;
+
+/// Observations kept per resource, older ones are dropped first.
+const MAX_OBSERVATIONS: usize = 50;
+
+/// Resources not seen changing state for this long are forgotten.
+const MAX_AGE_MILLIS: i64 = 30 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateObservation {
+    state: String,
+    observed_at: i64,
+    state_timestamp: Option<i64>,
+}
+
+impl StateObservation {
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The time of the transition, preferring the one reported by the connector.
+    pub fn timestamp(&self) -> i64 {
+        self.state_timestamp.unwrap_or(self.observed_at)
+    }
+
+    /// Whether [`Self::timestamp`] comes from the connector rather than the local clock.
+    pub fn is_reported(&self) -> bool {
+        self.state_timestamp.is_some()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StateHistory {
+    entries: HashMap<String, Vec<StateObservation>>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    /// Number of the last save, so that a slow write never replaces a newer one.
+    #[serde(skip)]
+    saves: u64,
+    #[serde(skip)]
+    written: Arc<Mutex<u64>>,
+}
+
+impl StateHistory {
+    pub fn persistent(name: &str) -> StateHistory {
+        let path = get_data_dir()
+            .join("history")
+            .join(format!("{}.json", name));
+
+        let mut history = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<StateHistory>(&content).ok())
+            .unwrap_or_default();
+
+        history.path = Some(path);
+        history.prune();
+        history
+    }
+
+    fn prune(&mut self) {
+        let oldest = Utc::now().timestamp_millis() - MAX_AGE_MILLIS;
+        self.entries.retain(|_, observations| {
+            observations
+                .last()
+                .map(|last| last.observed_at >= oldest)
+                .unwrap_or(false)
+        });
+    }
+
+    pub fn record(&mut self, key: &str, state: &str, state_timestamp: Option<i64>) -> bool {
+        let observations = self.entries.entry(key.to_string()).or_default();
+
+        let changed = observations
+            .last()
+            .map(|last| last.state != state)
+            .unwrap_or(true);
+
+        if changed {
+            observations.push(StateObservation {
+                state: state.to_string(),
+                observed_at: Utc::now().timestamp_millis(),
+                state_timestamp,
+            });
+            if observations.len() > MAX_OBSERVATIONS {
+                observations.drain(..observations.len() - MAX_OBSERVATIONS);
+            }
+        }
+        changed
+    }
+
+    pub fn get(&self, key: &str) -> &[StateObservation] {
+        self.entries.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Writes the history in the background, as it is saved from the update loop.
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        self.prune();
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        let contents = serde_json::to_string(self)?;
+        self.saves += 1;
+        let save = self.saves;
+        let written = self.written.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut written = written.lock().unwrap_or_else(|err| err.into_inner());
+            if *written > save {
+                return;
+            }
+            let result = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&path, contents));
+            match result {
+                Ok(()) => *written = save,
+                Err(err) => tracing::warn!("Failed to persist state history: {}", err),
+            }
+        });
+        Ok(())
+    }
+}
+
+pub fn format_timestamp(millis: i64) -> String {
+    DateTime::from_timestamp_millis(millis)
+        .map(|ts| {
+            ts.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| millis.to_string())
+}
+
+pub fn format_duration(millis: i64) -> String {
+    let secs = millis / 1000;
+    match secs {
+        _ if millis < 1000 => format!("{}ms", millis),
+        0..60 => format!("{:.1}s", millis as f64 / 1000.0),
+        60..3600 => format!("{}m {}s", secs / 60, secs % 60),
+        _ => format!("{}h {}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
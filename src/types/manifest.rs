@@ -0,0 +1,807 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Local};
+use edc_connector_client::types::{
+    asset::Asset, contract_definition::ContractDefinition, policy::PolicyDefinition,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+/// Assets, policy definitions and contract definitions described in JSON or YAML files,
+/// using the same representation as the management API.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Manifest {
+    /// Set on the manifests written by a backup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<BackupInfo>,
+    #[serde(default, alias = "policyDefinitions")]
+    pub policies: Vec<PolicyDefinition>,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+    #[serde(default)]
+    pub contract_definitions: Vec<ContractDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub connector: String,
+    pub management_url: String,
+    pub created_at: DateTime<Local>,
+    pub policies: usize,
+    pub assets: usize,
+    pub contract_definitions: usize,
+}
+
+/// The resources currently on a connector, compared against a [`Manifest`].
+#[derive(Debug, Default)]
+pub struct Current {
+    pub policies: Vec<PolicyDefinition>,
+    pub assets: Vec<Asset>,
+    pub contract_definitions: Vec<ContractDefinition>,
+}
+
+/// Kinds of resources handled by a manifest, in dependency order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ManifestKind {
+    Policy,
+    Asset,
+    ContractDefinition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlanStep {
+    pub kind: ManifestKind,
+    pub action: PlanAction,
+    pub id: String,
+    /// Paths of the changed values, only for updates.
+    pub changes: Vec<String>,
+    /// The body sent to the connector, `None` for deletes.
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    steps: Vec<PlanStep>,
+}
+
+const EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+impl Manifest {
+    /// Loads a manifest file, or all the manifest files of a directory.
+    pub fn load(path: &Path) -> anyhow::Result<Manifest> {
+        let files = if path.is_dir() {
+            let mut files = std::fs::read_dir(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_file() && extension(path).is_some())
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        } else {
+            vec![path.to_path_buf()]
+        };
+
+        let mut manifest = Manifest::default();
+        for file in files {
+            let parsed = Self::parse(&file)?;
+            manifest.backup = manifest.backup.or(parsed.backup);
+            manifest.policies.extend(parsed.policies);
+            manifest.assets.extend(parsed.assets);
+            manifest
+                .contract_definitions
+                .extend(parsed.contract_definitions);
+        }
+
+        check_unique(
+            ManifestKind::Policy,
+            manifest.policies.iter().map(|p| p.id()),
+        )?;
+        check_unique(ManifestKind::Asset, manifest.assets.iter().map(|a| a.id()))?;
+        check_unique(
+            ManifestKind::ContractDefinition,
+            manifest.contract_definitions.iter().map(|c| c.id()),
+        )?;
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to a single JSON or YAML file, or to a directory with one JSON file
+    /// per kind of resource. Existing files are not overwritten.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if extension(path).is_some() {
+            return write_new(path, self);
+        }
+
+        std::fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let info = Manifest {
+            backup: self.backup.clone(),
+            ..Default::default()
+        };
+        let policies = Manifest {
+            policies: self.policies.clone(),
+            ..Default::default()
+        };
+        let assets = Manifest {
+            assets: self.assets.clone(),
+            ..Default::default()
+        };
+        let contract_definitions = Manifest {
+            contract_definitions: self.contract_definitions.clone(),
+            ..Default::default()
+        };
+        for (name, manifest) in [
+            ("backup.json", info),
+            ("policies.json", policies),
+            ("assets.json", assets),
+            ("contract-definitions.json", contract_definitions),
+        ] {
+            write_new(&path.join(name), &manifest)?;
+        }
+        Ok(())
+    }
+
+    /// Changes the id of a resource, along with the references to it from contract definitions.
+    pub fn rename(&mut self, kind: ManifestKind, from: &str, to: &str) -> anyhow::Result<()> {
+        let set_id = |value: &mut Value| {
+            if value["@id"] == from {
+                value["@id"] = Value::from(to);
+            }
+        };
+        match kind {
+            ManifestKind::Policy => {
+                edit_values(&mut self.policies, set_id)?;
+                edit_values(&mut self.contract_definitions, |value| {
+                    for key in ["accessPolicyId", "contractPolicyId"] {
+                        if value[key] == from {
+                            value[key] = Value::from(to);
+                        }
+                    }
+                })?;
+            }
+            ManifestKind::Asset => {
+                edit_values(&mut self.assets, |value| {
+                    if value["@id"] == from {
+                        value["@id"] = Value::from(to);
+                        if value["properties"]["id"] == from {
+                            value["properties"]["id"] = Value::from(to);
+                        }
+                    }
+                })?;
+                edit_values(&mut self.contract_definitions, |value| {
+                    let Some(selector) = value["assetsSelector"].as_array_mut() else {
+                        return;
+                    };
+                    for criterion in selector.iter_mut().filter(|c| is_id_criterion(c)) {
+                        match &mut criterion["operandRight"] {
+                            Value::Array(ids) => ids
+                                .iter_mut()
+                                .filter(|id| **id == from)
+                                .for_each(|id| *id = Value::from(to)),
+                            id if *id == from => *id = Value::from(to),
+                            _ => {}
+                        }
+                    }
+                })?;
+            }
+            ManifestKind::ContractDefinition => {
+                edit_values(&mut self.contract_definitions, set_id)?
+            }
+        }
+        Ok(())
+    }
+
+    fn parse(path: &Path) -> anyhow::Result<Manifest> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let value: anyhow::Result<Value> = match extension(path) {
+            Some("json") => serde_json::from_str(&contents).map_err(anyhow::Error::from),
+            Some(_) => serde_norway::from_str(&contents).map_err(anyhow::Error::from),
+            None => anyhow::bail!(
+                "{} is not a manifest, expected one of {}",
+                path.display(),
+                EXTENSIONS.join(", ")
+            ),
+        };
+        value
+            .and_then(|mut value| {
+                default_types(&mut value);
+                Ok(serde_json::from_value(value)?)
+            })
+            .with_context(|| format!("Invalid manifest {}", path.display()))
+    }
+}
+
+/// `@type` can be omitted in manifests, it defaults to the type of each section, to `Set` for
+/// policies, to `DataAddress` for data addresses and to `Criterion` for asset selectors.
+fn default_types(manifest: &mut Value) {
+    for (section, ty) in [
+        ("policies", "PolicyDefinition"),
+        ("policyDefinitions", "PolicyDefinition"),
+        ("assets", "Asset"),
+        ("contractDefinitions", "ContractDefinition"),
+    ] {
+        let Some(entries) = manifest.get_mut(section).and_then(Value::as_array_mut) else {
+            continue;
+        };
+        for entry in entries.iter_mut().filter_map(Value::as_object_mut) {
+            entry
+                .entry("@type")
+                .or_insert_with(|| Value::String(ty.to_string()));
+            for (nested, ty) in [("policy", "Set"), ("dataAddress", "DataAddress")] {
+                if let Some(nested) = entry.get_mut(nested).and_then(Value::as_object_mut) {
+                    nested
+                        .entry("@type")
+                        .or_insert_with(|| Value::String(ty.to_string()));
+                }
+            }
+            if let Some(selector) = entry
+                .get_mut("assetsSelector")
+                .and_then(Value::as_array_mut)
+            {
+                for criterion in selector.iter_mut().filter_map(Value::as_object_mut) {
+                    criterion
+                        .entry("@type")
+                        .or_insert_with(|| Value::String("Criterion".to_string()));
+                }
+            }
+        }
+    }
+}
+
+fn edit_values<T: Serialize + DeserializeOwned>(
+    resources: &mut [T],
+    edit: impl Fn(&mut Value),
+) -> anyhow::Result<()> {
+    for resource in resources.iter_mut() {
+        let mut value = serde_json::to_value(&*resource)?;
+        edit(&mut value);
+        *resource = serde_json::from_value(value)?;
+    }
+    Ok(())
+}
+
+/// Selector criteria matching assets by id, e.g. `https://w3id.org/edc/v0.0.1/ns/id = my-asset`.
+fn is_id_criterion(criterion: &Value) -> bool {
+    criterion["operandLeft"]
+        .as_str()
+        .is_some_and(|left| left == "id" || left.ends_with("/id") || left.ends_with(":id"))
+}
+
+fn write_new(path: &Path, manifest: &Manifest) -> anyhow::Result<()> {
+    if path.exists() {
+        anyhow::bail!("{} already exists", path.display());
+    }
+    let contents = match extension(path) {
+        Some("json") => serde_json::to_string_pretty(manifest)?,
+        _ => serde_norway::to_string(manifest)?,
+    };
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    EXTENSIONS.iter().find(|e| **e == ext).copied()
+}
+
+fn check_unique<'a>(kind: ManifestKind, ids: impl Iterator<Item = &'a str>) -> anyhow::Result<()> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            anyhow::bail!("The {} {} is defined more than once", kind, id);
+        }
+    }
+    Ok(())
+}
+
+impl ManifestKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ManifestKind::Policy => "policy",
+            ManifestKind::Asset => "asset",
+            ManifestKind::ContractDefinition => "contract definition",
+        }
+    }
+}
+
+impl Display for ManifestKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl PlanAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlanAction::Create => "create",
+            PlanAction::Update => "update",
+            PlanAction::Delete => "delete",
+        }
+    }
+}
+
+impl Plan {
+    /// Creates and updates come in dependency order, deletes in the reverse one.
+    /// Resources missing from the manifest are only deleted when `prune` is set.
+    pub fn new(manifest: &Manifest, current: &Current, prune: bool) -> anyhow::Result<Plan> {
+        let mut upserts = vec![];
+        let mut deletes = vec![];
+
+        for (kind, desired, existing) in [
+            (
+                ManifestKind::Policy,
+                to_values(&manifest.policies)?,
+                to_values(&current.policies)?,
+            ),
+            (
+                ManifestKind::Asset,
+                to_values(&manifest.assets)?,
+                to_values(&current.assets)?,
+            ),
+            (
+                ManifestKind::ContractDefinition,
+                to_values(&manifest.contract_definitions)?,
+                to_values(&current.contract_definitions)?,
+            ),
+        ] {
+            let (kind_upserts, kind_deletes) = diff(kind, desired, existing, prune);
+            upserts.extend(kind_upserts);
+            deletes.extend(kind_deletes);
+        }
+
+        deletes.reverse();
+        upserts.extend(deletes);
+        Ok(Plan { steps: upserts })
+    }
+
+    pub fn retain(&mut self, keep: impl FnMut(&PlanStep) -> bool) {
+        self.steps.retain(keep);
+    }
+
+    pub fn steps(&self) -> &[PlanStep] {
+        &self.steps
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn summary(&self) -> String {
+        let count = |action| self.steps.iter().filter(|s| s.action == action).count();
+        format!(
+            "{} to create, {} to update, {} to delete",
+            count(PlanAction::Create),
+            count(PlanAction::Update),
+            count(PlanAction::Delete)
+        )
+    }
+}
+
+fn to_values<T: Serialize>(resources: &[T]) -> anyhow::Result<Vec<(String, Value)>> {
+    resources
+        .iter()
+        .map(|resource| {
+            let value = serde_json::to_value(resource)?;
+            let id = value["@id"].as_str().unwrap_or_default().to_string();
+            Ok((id, value))
+        })
+        .collect()
+}
+
+fn diff(
+    kind: ManifestKind,
+    desired: Vec<(String, Value)>,
+    existing: Vec<(String, Value)>,
+    prune: bool,
+) -> (Vec<PlanStep>, Vec<PlanStep>) {
+    let mut existing = existing.into_iter().collect::<HashMap<_, _>>();
+    let mut upserts = vec![];
+
+    for (id, body) in desired {
+        let (action, changes) = match existing.remove(&id) {
+            None => (PlanAction::Create, vec![]),
+            Some(current) => {
+                let changes = changed_paths(&normalize(kind, &body, current), &body, "");
+                (PlanAction::Update, changes)
+            }
+        };
+        if action == PlanAction::Update && changes.is_empty() {
+            continue;
+        }
+        upserts.push(PlanStep {
+            kind,
+            action,
+            id,
+            changes,
+            body: Some(body),
+        });
+    }
+
+    let mut deletes = existing
+        .into_keys()
+        .filter(|_| prune)
+        .map(|id| PlanStep {
+            kind,
+            action: PlanAction::Delete,
+            id,
+            changes: vec![],
+            body: None,
+        })
+        .collect::<Vec<_>>();
+    deletes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    (upserts, deletes)
+}
+
+/// Ids generated by the connector (asset `properties.id`, `policy.@id`) are not a change when the
+/// manifest omits them.
+fn normalize(kind: ManifestKind, desired: &Value, mut current: Value) -> Value {
+    let (parent, key) = match kind {
+        ManifestKind::Asset => ("properties", "id"),
+        ManifestKind::Policy => ("policy", "@id"),
+        ManifestKind::ContractDefinition => return current,
+    };
+    if desired[parent].get(key).is_none() {
+        if let Some(object) = current[parent].as_object_mut() {
+            object.remove(key);
+        }
+    }
+    current
+}
+
+/// A value that differs between two JSON documents, `Null` when missing on one side.
+#[derive(Debug, Clone)]
+pub struct Change {
+    /// The dotted path, for display.
+    pub path: String,
+    /// The keys leading to the value, as keys may contain dots (e.g. JSON-LD IRIs).
+    pub keys: Vec<String>,
+    pub left: Value,
+    pub right: Value,
+}
+
+/// Dotted paths of the values that differ between two JSON documents.
+pub fn changed_paths(left: &Value, right: &Value, prefix: &str) -> Vec<String> {
+    changed_values(left, right, prefix)
+        .into_iter()
+        .map(|change| change.path)
+        .collect()
+}
+
+/// The values that differ between two JSON documents, objects are compared key by key.
+pub fn changed_values(left: &Value, right: &Value, prefix: &str) -> Vec<Change> {
+    let mut changes = vec![];
+    collect_changes(left, right, prefix, &mut vec![], &mut changes);
+    changes
+}
+
+fn collect_changes(
+    left: &Value,
+    right: &Value,
+    prefix: &str,
+    keys: &mut Vec<String>,
+    out: &mut Vec<Change>,
+) {
+    match (left, right) {
+        (Value::Object(l), Value::Object(r)) => {
+            let mut all = l.keys().chain(r.keys()).collect::<Vec<_>>();
+            all.sort();
+            all.dedup();
+            for key in all {
+                keys.push(key.clone());
+                collect_changes(
+                    l.get(key).unwrap_or(&Value::Null),
+                    r.get(key).unwrap_or(&Value::Null),
+                    prefix,
+                    keys,
+                    out,
+                );
+                keys.pop();
+            }
+        }
+        (l, r) if l == r => {}
+        (l, r) => {
+            let path = std::iter::once(prefix)
+                .filter(|prefix| !prefix.is_empty())
+                .chain(keys.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(".");
+            out.push(Change {
+                path: if path.is_empty() {
+                    String::from("<root>")
+                } else {
+                    path
+                },
+                keys: keys.clone(),
+                left: l.clone(),
+                right: r.clone(),
+            })
+        }
+    }
+}
+
+/// A manifest path given on the command line or in the apply view.
+pub fn manifest_path(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs_next::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    use ManifestKind::{Asset as A, ContractDefinition as C, Policy as P};
+    use PlanAction::{Create, Delete, Update};
+
+    type Step<'a> = (ManifestKind, PlanAction, &'a str);
+
+    /// A JSON pointer with the value expected there.
+    type Pointed = (&'static str, Value);
+
+    /// A manifest as read from a file, with the `@type`s defaulted.
+    fn manifest(mut value: Value) -> Manifest {
+        default_types(&mut value);
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn current(value: Value) -> Current {
+        let manifest = manifest(value);
+        Current {
+            policies: manifest.policies,
+            assets: manifest.assets,
+            contract_definitions: manifest.contract_definitions,
+        }
+    }
+
+    fn policy(id: &str) -> Value {
+        json!({ "@id": id, "policy": { "permission": [] } })
+    }
+
+    fn asset(id: &str, name: &str) -> Value {
+        json!({
+            "@id": id,
+            "properties": { "name": name },
+            "dataAddress": { "type": "HttpData", "baseUrl": "http://example.com" },
+        })
+    }
+
+    fn contract_definition(id: &str, policy_id: &str, asset_id: &str) -> Value {
+        json!({
+            "@id": id,
+            "accessPolicyId": policy_id,
+            "contractPolicyId": policy_id,
+            "assetsSelector": [{
+                "operandLeft": "https://w3id.org/edc/v0.0.1/ns/id",
+                "operator": "=",
+                "operandRight": asset_id,
+            }],
+        })
+    }
+
+    fn steps(plan: &Plan) -> Vec<Step<'_>> {
+        plan.steps()
+            .iter()
+            .map(|step| (step.kind, step.action, step.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn plans_steps_in_dependency_order() {
+        let all = json!({
+            "contractDefinitions": [contract_definition("c1", "p1", "a1")],
+            "assets": [asset("a1", "one")],
+            "policies": [policy("p1")],
+        });
+        let cases: Vec<(&str, Value, Value, bool, Vec<Step>)> = vec![
+            (
+                "creates policies, then assets, then contract definitions",
+                all.clone(),
+                json!({}),
+                false,
+                vec![(P, Create, "p1"), (A, Create, "a1"), (C, Create, "c1")],
+            ),
+            (
+                "identical resources are left alone",
+                all.clone(),
+                all.clone(),
+                true,
+                vec![],
+            ),
+            (
+                "extra resources are kept without prune",
+                json!({}),
+                all.clone(),
+                false,
+                vec![],
+            ),
+            (
+                "extra resources are deleted in reverse order with prune",
+                json!({}),
+                all.clone(),
+                true,
+                vec![(C, Delete, "c1"), (A, Delete, "a1"), (P, Delete, "p1")],
+            ),
+            (
+                "changed resources are updated",
+                json!({ "assets": [asset("a1", "new"), asset("a2", "two")] }),
+                json!({ "assets": [asset("a1", "old"), asset("a2", "two")] }),
+                false,
+                vec![(A, Update, "a1")],
+            ),
+            (
+                "creates and updates come before deletes",
+                json!({ "policies": [policy("p2")], "assets": [asset("a1", "new")] }),
+                json!({ "policies": [policy("p1")], "assets": [asset("a1", "old")] }),
+                true,
+                vec![(P, Create, "p2"), (A, Update, "a1"), (P, Delete, "p1")],
+            ),
+        ];
+
+        for (name, desired, existing, prune, expected) in cases {
+            let plan = Plan::new(&manifest(desired), &current(existing), prune).unwrap();
+            assert_eq!(steps(&plan), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn ignores_generated_ids_missing_from_the_manifest() {
+        let mut generated_asset = asset("a1", "one");
+        generated_asset["properties"]["id"] = json!("a1");
+        let mut generated_policy = policy("p1");
+        generated_policy["policy"]["@id"] = json!("generated");
+        let mut other_asset_id = asset("a1", "one");
+        other_asset_id["properties"]["id"] = json!("other");
+
+        let cases: Vec<(&str, Value, Value, Vec<String>)> = vec![
+            (
+                "asset properties.id",
+                json!({ "assets": [asset("a1", "one")] }),
+                json!({ "assets": [generated_asset.clone()] }),
+                vec![],
+            ),
+            (
+                "policy @id",
+                json!({ "policies": [policy("p1")] }),
+                json!({ "policies": [generated_policy] }),
+                vec![],
+            ),
+            (
+                "asset properties.id set in the manifest",
+                json!({ "assets": [other_asset_id] }),
+                json!({ "assets": [generated_asset] }),
+                vec![String::from("properties.id")],
+            ),
+        ];
+
+        for (name, desired, existing, expected) in cases {
+            let plan = Plan::new(&manifest(desired), &current(existing), false).unwrap();
+            let changes = plan
+                .steps()
+                .iter()
+                .flat_map(|step| step.changes.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(changes, expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn rename_rewrites_references() {
+        let cases: Vec<(ManifestKind, &str, Vec<Pointed>)> = vec![
+            (
+                P,
+                "p1",
+                vec![
+                    ("/policies/0/@id", json!("renamed")),
+                    ("/contractDefinitions/0/accessPolicyId", json!("renamed")),
+                    ("/contractDefinitions/0/contractPolicyId", json!("renamed")),
+                    ("/contractDefinitions/1/accessPolicyId", json!("p2")),
+                ],
+            ),
+            (
+                A,
+                "a1",
+                vec![
+                    ("/assets/0/@id", json!("renamed")),
+                    ("/assets/0/properties/id", json!("renamed")),
+                    (
+                        "/contractDefinitions/0/assetsSelector/0/operandRight",
+                        json!("renamed"),
+                    ),
+                    (
+                        "/contractDefinitions/1/assetsSelector/0/operandRight",
+                        json!(["renamed", "a2"]),
+                    ),
+                ],
+            ),
+            (
+                C,
+                "c1",
+                vec![
+                    ("/contractDefinitions/0/@id", json!("renamed")),
+                    ("/contractDefinitions/1/@id", json!("c2")),
+                ],
+            ),
+        ];
+
+        for (kind, from, expected) in cases {
+            let mut with_id = asset("a1", "one");
+            with_id["properties"]["id"] = json!("a1");
+            let mut selecting_many = contract_definition("c2", "p2", "a1");
+            selecting_many["assetsSelector"][0]["operator"] = json!("in");
+            selecting_many["assetsSelector"][0]["operandRight"] = json!(["a1", "a2"]);
+            let mut manifest = manifest(json!({
+                "policies": [policy("p1"), policy("p2")],
+                "assets": [with_id, asset("a2", "two")],
+                "contractDefinitions": [contract_definition("c1", "p1", "a1"), selecting_many],
+            }));
+
+            manifest.rename(kind, from, "renamed").unwrap();
+
+            let value = serde_json::to_value(&manifest).unwrap();
+            for (pointer, expected) in expected {
+                assert_eq!(
+                    value.pointer(pointer),
+                    Some(&expected),
+                    "{:?} {}",
+                    kind,
+                    pointer
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn defaults_types() {
+        let mut value = json!({
+            "policies": [{ "@id": "p1", "policy": {} }],
+            "assets": [{ "@id": "a1", "dataAddress": {} }],
+            "contractDefinitions": [{ "@id": "c1", "assetsSelector": [{}] }],
+        });
+        default_types(&mut value);
+        for (pointer, expected) in [
+            ("/policies/0/@type", "PolicyDefinition"),
+            ("/policies/0/policy/@type", "Set"),
+            ("/assets/0/@type", "Asset"),
+            ("/assets/0/dataAddress/@type", "DataAddress"),
+            ("/contractDefinitions/0/@type", "ContractDefinition"),
+            ("/contractDefinitions/0/assetsSelector/0/@type", "Criterion"),
+        ] {
+            assert_eq!(
+                value.pointer(pointer),
+                Some(&json!(expected)),
+                "{}",
+                pointer
+            );
+        }
+    }
+
+    #[test]
+    fn parses_yaml() {
+        let mut value: Value = serde_norway::from_str(
+            "assets:\n  - '@id': a1\n    properties:\n      name: one\n    dataAddress:\n      type: HttpData\n",
+        )
+        .unwrap();
+        default_types(&mut value);
+        let manifest: Manifest = serde_json::from_value(value).unwrap();
+        assert_eq!(manifest.assets[0].id(), "a1");
+    }
+}
@@ -8,6 +8,7 @@ use strum::{EnumString, VariantNames};
 pub enum Nav {
     #[default]
     ConnectorsList,
+    Dashboard,
     AssetsList,
     PoliciesList,
     ContractDefinitionsList,
@@ -16,6 +17,11 @@ pub enum Nav {
     TransferProcesses,
     Edrs,
     DataPlanes,
+    ParticipantContexts,
+    Requests,
+    Notifications,
+    Apply,
+    Compare,
 }
 
 impl FromStr for Nav {
@@ -24,8 +30,14 @@ impl FromStr for Nav {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "connectors" => Ok(Nav::ConnectorsList),
+            "dashboard" => Ok(Nav::Dashboard),
             "assets" => Ok(Nav::AssetsList),
             "policies" => Ok(Nav::PoliciesList),
+            "participants" => Ok(Nav::ParticipantContexts),
+            "requests" => Ok(Nav::Requests),
+            "notifications" => Ok(Nav::Notifications),
+            "apply" => Ok(Nav::Apply),
+            "compare" => Ok(Nav::Compare),
             _ => bail!("Command {} not recognized", s),
         }
     }
@@ -36,6 +48,7 @@ impl FromStr for Nav {
 pub enum Menu {
     #[default]
     Connectors,
+    Dashboard,
     Assets,
     Policies,
     ContractDefinitions,
@@ -44,6 +57,11 @@ pub enum Menu {
     TransferProcesses,
     Edrs,
     DataPlanes,
+    ParticipantContexts,
+    Requests,
+    Notifications,
+    Apply,
+    Compare,
 }
 
 impl Menu {
@@ -59,6 +77,7 @@ impl From<Nav> for Menu {
     fn from(val: Nav) -> Self {
         match val {
             Nav::ConnectorsList => Menu::Connectors,
+            Nav::Dashboard => Menu::Dashboard,
             Nav::AssetsList => Menu::Assets,
             Nav::PoliciesList => Menu::Policies,
             Nav::ContractDefinitionsList => Menu::ContractDefinitions,
@@ -67,6 +86,11 @@ impl From<Nav> for Menu {
             Nav::ContractAgreements => Menu::ContractAgreements,
             Nav::Edrs => Menu::Edrs,
             Nav::DataPlanes => Menu::DataPlanes,
+            Nav::ParticipantContexts => Menu::ParticipantContexts,
+            Nav::Requests => Menu::Requests,
+            Nav::Notifications => Menu::Notifications,
+            Nav::Apply => Menu::Apply,
+            Nav::Compare => Menu::Compare,
         }
     }
 }
@@ -75,6 +99,7 @@ impl From<Menu> for Nav {
     fn from(val: Menu) -> Self {
         match val {
             Menu::Connectors => Nav::ConnectorsList,
+            Menu::Dashboard => Nav::Dashboard,
             Menu::Assets => Nav::AssetsList,
             Menu::Policies => Nav::PoliciesList,
             Menu::ContractDefinitions => Nav::ContractDefinitionsList,
@@ -83,6 +108,11 @@ impl From<Menu> for Nav {
             Menu::ContractAgreements => Nav::ContractAgreements,
             Menu::Edrs => Nav::Edrs,
             Menu::DataPlanes => Nav::DataPlanes,
+            Menu::ParticipantContexts => Nav::ParticipantContexts,
+            Menu::Requests => Nav::Requests,
+            Menu::Notifications => Nav::Notifications,
+            Menu::Apply => Nav::Apply,
+            Menu::Compare => Nav::Compare,
         }
     }
 }
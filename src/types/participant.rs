@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParticipantContext {
+    #[serde(rename = "@id", alias = "participantContextId", alias = "id")]
+    id: String,
+    #[serde(default)]
+    identity: Option<String>,
+    #[serde(default)]
+    state: Option<Value>,
+}
+
+impl ParticipantContext {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn identity(&self) -> Option<&str> {
+        self.identity.as_deref()
+    }
+
+    pub fn state(&self) -> Option<String> {
+        self.state.as_ref().map(|state| match state {
+            Value::String(state) => state.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewParticipantContext {
+    pub id: Option<String>,
+    pub identity: String,
+}
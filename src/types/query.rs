@@ -0,0 +1,658 @@
+use std::cmp::Ordering;
+
+use anyhow::bail;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use edc_connector_client::types::{
+    properties::ToValue,
+    query::{Criterion, Query},
+};
+use serde_json::Value;
+
+/// Arbitrary JSON operand for query criteria, the client only accepts strings out of the box.
+#[derive(Debug, Clone)]
+pub struct QueryValue(Value);
+
+impl QueryValue {
+    pub fn new(value: impl Into<Value>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl ToValue for QueryValue {
+    fn into_value(self) -> Value {
+        self.0
+    }
+}
+
+/// Names and numeric codes of the states of entities stored with a numeric `state`.
+pub type StateCodes = &'static [(&'static str, u32)];
+
+/// Operators accepted in filter expressions, the word operators being matched case-insensitively.
+const OPERATORS: [&str; 10] = [
+    "=", "!=", "<", ">", "<=", ">=", "in", "like", "ilike", "contains",
+];
+
+/// A criterion of a filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub field: String,
+    pub operator: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Symbol(String),
+}
+
+/// Parses a filter expression such as `state = STARTED and assetId like 'foo%'`.
+///
+/// Unquoted values are typed: numbers and `true`/`false` are kept as JSON numbers and booleans,
+/// ISO dates and date-times (UTC unless an offset is given) become epoch milliseconds, anything
+/// else is a string. `in` takes a list like `(a, 'b c', 3)`.
+pub fn parse_expression(input: &str) -> anyhow::Result<Vec<Condition>> {
+    let tokens = tokenize(input)?;
+    let mut tokens = tokens.into_iter().peekable();
+    let mut conditions = vec![];
+
+    while tokens.peek().is_some() {
+        if !conditions.is_empty() {
+            match tokens.next() {
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("and") => {}
+                Some(token) => bail!("Expected `and` before {}", describe(&token)),
+                None => unreachable!(),
+            }
+        }
+
+        let field = match tokens.next() {
+            Some(Token::Word(word)) | Some(Token::Quoted(word)) => word,
+            Some(token) => bail!("Expected a field, found {}", describe(&token)),
+            None => bail!("Expected a field after `and`"),
+        };
+
+        let operator = match tokens.next() {
+            Some(Token::Symbol(symbol)) if OPERATORS.contains(&symbol.as_str()) => symbol,
+            Some(Token::Word(word)) if OPERATORS.contains(&word.to_lowercase().as_str()) => {
+                word.to_lowercase()
+            }
+            Some(token) => bail!(
+                "Unknown operator {} after {}, expected one of {}",
+                describe(&token),
+                field,
+                OPERATORS.join(", ")
+            ),
+            None => bail!("Expected an operator after {}", field),
+        };
+
+        let value = match tokens.next() {
+            Some(Token::Symbol(open)) if open == "(" || open == "[" => {
+                let close = if open == "(" { ")" } else { "]" };
+                let mut values = vec![];
+                loop {
+                    match tokens.next() {
+                        Some(Token::Symbol(symbol)) if symbol == close && values.is_empty() => {
+                            break
+                        }
+                        Some(Token::Word(word)) => values.push(typed(word)),
+                        Some(Token::Quoted(text)) => values.push(Value::String(text)),
+                        Some(token) => bail!("Expected a list value, found {}", describe(&token)),
+                        None => bail!("Unclosed list for {}", field),
+                    }
+                    match tokens.next() {
+                        Some(Token::Symbol(symbol)) if symbol == "," => {}
+                        Some(Token::Symbol(symbol)) if symbol == close => break,
+                        Some(token) => {
+                            bail!("Expected `,` or `{}`, found {}", close, describe(&token))
+                        }
+                        None => bail!("Unclosed list for {}", field),
+                    }
+                }
+                Value::Array(values)
+            }
+            Some(Token::Word(word)) => typed(word),
+            Some(Token::Quoted(text)) => Value::String(text),
+            Some(token) => bail!("Expected a value for {}, found {}", field, describe(&token)),
+            None => bail!("Expected a value after {} {}", field, operator),
+        };
+
+        match (operator.as_str(), &value) {
+            ("in", Value::Array(_)) => {}
+            ("in", _) => bail!(
+                "`in` expects a list such as ({}, ...)",
+                display_value(&value)
+            ),
+            (_, Value::Array(_)) => bail!("Only `in` accepts a list, found `{}`", operator),
+            ("like" | "ilike", Value::String(_)) => {}
+            ("like" | "ilike", _) => bail!(
+                "`{}` expects a text pattern, quote {} to match it as text",
+                operator,
+                display_value(&value)
+            ),
+            _ => {}
+        }
+
+        conditions.push(Condition {
+            field,
+            operator,
+            value,
+        });
+    }
+
+    Ok(conditions)
+}
+
+/// Replaces the state names compared with `state` by their numeric codes, numbers being kept.
+pub fn encode_states(
+    conditions: Vec<Condition>,
+    codes: StateCodes,
+) -> anyhow::Result<Vec<Condition>> {
+    if codes.is_empty() {
+        return Ok(conditions);
+    }
+    let encode = |value: Value| match value {
+        Value::String(name) => codes
+            .iter()
+            .find(|(state, _)| state.eq_ignore_ascii_case(&name))
+            .map(|(_, code)| Value::from(*code))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown state {}, expected one of {}",
+                    name,
+                    codes
+                        .iter()
+                        .map(|(state, _)| *state)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }),
+        value => Ok(value),
+    };
+
+    conditions
+        .into_iter()
+        .map(|condition| {
+            if condition.field != "state" {
+                return Ok(condition);
+            }
+            let value = match condition.value {
+                Value::Array(values) => Value::Array(
+                    values
+                        .into_iter()
+                        .map(encode)
+                        .collect::<anyhow::Result<_>>()?,
+                ),
+                value => encode(value)?,
+            };
+            Ok(Condition { value, ..condition })
+        })
+        .collect()
+}
+
+/// The query of the entities in a state, compared by its code as the state is stored numerically.
+pub fn state_query(state: &str, codes: StateCodes) -> Query {
+    let value = codes
+        .iter()
+        .find(|(name, _)| *name == state)
+        .map(|(_, code)| QueryValue::new(*code))
+        .unwrap_or_else(|| QueryValue::new(state));
+    Query::builder().filter("state", "=", value).build()
+}
+
+/// The state names of numeric state codes, for showing a query as it was typed.
+pub fn decode_states(field: &str, value: &Value, codes: StateCodes) -> Value {
+    let decode = |value: &Value| {
+        codes
+            .iter()
+            .find(|(_, code)| value.as_u64() == Some(u64::from(*code)))
+            .map(|(state, _)| Value::from(*state))
+            .unwrap_or_else(|| value.clone())
+    };
+    match value {
+        _ if field != "state" => value.clone(),
+        Value::Array(values) => Value::Array(values.iter().map(decode).collect()),
+        value => decode(value),
+    }
+}
+
+/// Formats criteria back into the filter expression syntax.
+pub fn format_expression<'a>(
+    criteria: impl IntoIterator<Item = (&'a str, &'a str, &'a Value)>,
+) -> String {
+    criteria
+        .into_iter()
+        .map(|(field, operator, value)| {
+            format!(
+                "{} {} {}",
+                display_text(field),
+                operator,
+                display_value(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
+
+/// Evaluates criteria against the fields of an entity, for APIs without query support.
+pub fn matches(criteria: &[Criterion], fields: &Value) -> bool {
+    criteria.iter().all(|criterion| {
+        let actual = fields.get(criterion.operand_left()).unwrap_or(&Value::Null);
+        let expected = &criterion.operand_right().0;
+        match criterion.operator().to_lowercase().as_str() {
+            "=" => same(actual, expected),
+            "!=" => !same(actual, expected),
+            "<" => compare_values(actual, expected) == Ordering::Less,
+            ">" => compare_values(actual, expected) == Ordering::Greater,
+            "<=" => compare_values(actual, expected) != Ordering::Greater,
+            ">=" => compare_values(actual, expected) != Ordering::Less,
+            "in" => expected
+                .as_array()
+                .map(|values| values.iter().any(|value| same(actual, value)))
+                .unwrap_or(false),
+            "like" => like(&value_text(actual), &value_text(expected)),
+            "ilike" => like(
+                &value_text(actual).to_lowercase(),
+                &value_text(expected).to_lowercase(),
+            ),
+            "contains" => match actual {
+                Value::Array(values) => values.iter().any(|value| same(value, expected)),
+                value => value_text(value).contains(&value_text(expected)),
+            },
+            _ => false,
+        }
+    })
+}
+
+/// Orders numbers numerically and anything else by its text.
+pub fn compare_values(left: &Value, right: &Value) -> Ordering {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(left), Some(right)) => left.partial_cmp(&right).unwrap_or(Ordering::Equal),
+        _ => value_text(left).cmp(&value_text(right)),
+    }
+}
+
+/// Values equal as JSON or as text, fields being mostly displayed as strings.
+fn same(left: &Value, right: &Value) -> bool {
+    left == right || value_text(left) == value_text(right)
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// SQL `LIKE` matching, `%` standing for any text and `_` for a single character.
+fn like(text: &str, pattern: &str) -> bool {
+    let text = text.chars().collect::<Vec<_>>();
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let mut matched = vec![false; text.len() + 1];
+    matched[0] = true;
+    for p in pattern {
+        let previous = matched.clone();
+        match p {
+            '%' => {
+                for idx in 1..=text.len() {
+                    matched[idx] = matched[idx] || matched[idx - 1];
+                }
+            }
+            p => {
+                matched[0] = false;
+                for idx in 1..=text.len() {
+                    matched[idx] = previous[idx - 1] && (p == '_' || p == text[idx - 1]);
+                }
+            }
+        }
+    }
+    matched[text.len()]
+}
+
+fn tokenize(input: &str) -> anyhow::Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '\'' | '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        // A doubled quote stands for the quote itself.
+                        Some(q) if q == c && chars.peek() == Some(&c) => {
+                            chars.next();
+                            text.push(c);
+                        }
+                        Some(q) if q == c => break,
+                        Some(other) => text.push(other),
+                        None => bail!("Unclosed quote in {}", input),
+                    }
+                }
+                tokens.push(Token::Quoted(text));
+            }
+            '(' | ')' | '[' | ']' | ',' => {
+                chars.next();
+                tokens.push(Token::Symbol(c.to_string()));
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let mut symbol = c.to_string();
+                if chars.peek() == Some(&'=') {
+                    chars.next();
+                    symbol.push('=');
+                }
+                if symbol == "!" {
+                    bail!("Unknown operator `!`, did you mean `!=`?");
+                }
+                tokens.push(Token::Symbol(symbol));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || is_special(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_special(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '"' | '(' | ')' | '[' | ']' | ',' | '=' | '!' | '<' | '>'
+    )
+}
+
+fn typed(word: String) -> Value {
+    match word.as_str() {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => word
+            .parse::<i64>()
+            .map(Value::from)
+            .ok()
+            .or_else(|| {
+                word.parse::<f64>()
+                    .ok()
+                    .and_then(|n| serde_json::Number::from_f64(n).map(Value::Number))
+            })
+            .or_else(|| epoch_millis(&word).map(Value::from))
+            .unwrap_or(Value::String(word)),
+    }
+}
+
+fn epoch_millis(word: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(word)
+        .map(|ts| ts.timestamp_millis())
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(word, "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .map(|ts| ts.and_utc().timestamp_millis())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(word, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|ts| ts.and_utc().timestamp_millis())
+        })
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(word) => format!("`{}`", word),
+        Token::Quoted(text) => format!("'{}'", text),
+        Token::Symbol(symbol) => format!("`{}`", symbol),
+    }
+}
+
+/// Text unquoted when it reads back as the same string.
+fn display_text(text: &str) -> String {
+    let bare = !text.is_empty()
+        && !text.chars().any(|c| c.is_whitespace() || is_special(c))
+        && !text.eq_ignore_ascii_case("and")
+        && typed(text.to_string()) == Value::String(text.to_string());
+    if bare {
+        text.to_string()
+    } else {
+        format!("'{}'", text.replace('\'', "''"))
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => display_text(text),
+        Value::Array(values) => format!(
+            "({})",
+            values
+                .iter()
+                .map(display_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Object(_) => display_text(&value.to_string()),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const STATES: StateCodes = &[("STARTED", 600), ("COMPLETED", 800)];
+
+    fn condition(field: &str, operator: &str, value: Value) -> Condition {
+        Condition {
+            field: field.to_string(),
+            operator: operator.to_string(),
+            value,
+        }
+    }
+
+    fn round_trip(input: &str) -> String {
+        let conditions = parse_expression(input).unwrap();
+        format_expression(
+            conditions
+                .iter()
+                .map(|c| (c.field.as_str(), c.operator.as_str(), &c.value)),
+        )
+    }
+
+    #[test]
+    fn tokenizes_words_symbols_and_quotes() {
+        assert_eq!(
+            tokenize("a>=1 and b in ('x y',2)").unwrap(),
+            vec![
+                Token::Word("a".into()),
+                Token::Symbol(">=".into()),
+                Token::Word("1".into()),
+                Token::Word("and".into()),
+                Token::Word("b".into()),
+                Token::Word("in".into()),
+                Token::Symbol("(".into()),
+                Token::Quoted("x y".into()),
+                Token::Symbol(",".into()),
+                Token::Word("2".into()),
+                Token::Symbol(")".into()),
+            ]
+        );
+        assert!(tokenize("a ! b").is_err());
+        assert!(tokenize("a = 'open").is_err());
+    }
+
+    #[test]
+    fn unescapes_doubled_quotes() {
+        assert_eq!(
+            parse_expression("name = 'it''s' and title = \"say \"\"hi\"\"\"").unwrap(),
+            vec![
+                condition("name", "=", json!("it's")),
+                condition("title", "=", json!("say \"hi\"")),
+            ]
+        );
+    }
+
+    #[test]
+    fn types_unquoted_values() {
+        let values = parse_expression("a = 1 and b = 1.5 and c = true and d = x and e = '1'")
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![json!(1), json!(1.5), json!(true), json!("x"), json!("1")]
+        );
+    }
+
+    #[test]
+    fn parses_dates_as_epoch_millis() {
+        let values = parse_expression(
+            "a > 2024-01-01 and b < 2024-01-01T10:00:00 and c < 2024-01-01T10:00:00+02:00",
+        )
+        .unwrap()
+        .into_iter()
+        .map(|c| c.value)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                json!(1704067200000_i64),
+                json!(1704103200000_i64),
+                json!(1704096000000_i64),
+            ]
+        );
+        assert_eq!(
+            parse_expression("a = '2024-01-01'").unwrap()[0].value,
+            json!("2024-01-01")
+        );
+    }
+
+    #[test]
+    fn parses_in_lists() {
+        assert_eq!(
+            parse_expression("state in (STARTED, 'a b', 3)").unwrap(),
+            vec![condition("state", "in", json!(["STARTED", "a b", 3]))]
+        );
+        assert_eq!(
+            parse_expression("id IN [x]").unwrap(),
+            vec![condition("id", "in", json!(["x"]))]
+        );
+        assert_eq!(
+            parse_expression("id in ()").unwrap(),
+            vec![condition("id", "in", json!([]))]
+        );
+        assert!(parse_expression("id in x").is_err());
+        assert!(parse_expression("id in (x").is_err());
+        assert!(parse_expression("id = (x)").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert!(parse_expression("a = 1 b = 2").is_err());
+        assert!(parse_expression("a ~ 1").is_err());
+        assert!(parse_expression("a =").is_err());
+        assert!(parse_expression("a like 1").is_err());
+        assert!(parse_expression("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn formats_back_to_the_same_conditions() {
+        for input in [
+            "state = STARTED",
+            "assetId like 'foo%' and count >= 3",
+            "name = 'it''s' and label = 'a b'",
+            "id in (a, 'b c', 3) and flag != true",
+            "code = '42' and word = 'and' and day = '2024-01-01'",
+            "price < 1.5 and createdAt > 2024-01-01",
+            "'odd field' = \"(x)\"",
+        ] {
+            assert_eq!(
+                parse_expression(&round_trip(input)).unwrap(),
+                parse_expression(input).unwrap(),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            round_trip("x IN [a,b] AND y LIKE 'z%'"),
+            "x in (a, b) and y like z%"
+        );
+        assert_eq!(
+            round_trip("a = 'it''s' and b = '1'"),
+            "a = 'it''s' and b = '1'"
+        );
+    }
+
+    #[test]
+    fn encodes_and_decodes_state_names() {
+        let conditions =
+            parse_expression("state = started and state in (COMPLETED, 600) and id = STARTED")
+                .unwrap();
+        let encoded = encode_states(conditions, STATES).unwrap();
+        assert_eq!(
+            encoded,
+            vec![
+                condition("state", "=", json!(600)),
+                condition("state", "in", json!([800, 600])),
+                condition("id", "=", json!("STARTED")),
+            ]
+        );
+        assert_eq!(
+            decode_states("state", &json!([800, 1]), STATES),
+            json!(["COMPLETED", 1])
+        );
+        assert_eq!(decode_states("id", &json!(600), STATES), json!(600));
+        assert!(encode_states(parse_expression("state = DONE").unwrap(), STATES).is_err());
+        assert_eq!(
+            encode_states(parse_expression("state = DONE").unwrap(), &[]).unwrap(),
+            vec![condition("state", "=", json!("DONE"))]
+        );
+    }
+
+    #[test]
+    fn matches_criteria_against_fields() {
+        let fields = json!({"id": "abc-1", "state": "AVAILABLE", "count": 5, "tags": ["x"]});
+        let matching = |expression: &str| {
+            let criteria = parse_expression(expression)
+                .unwrap()
+                .into_iter()
+                .map(|c| Criterion::new(&c.field, &c.operator, QueryValue::new(c.value)))
+                .collect::<Vec<_>>();
+            matches(&criteria, &fields)
+        };
+        assert!(matching(
+            "id like 'ab%' and id like '_bc-1' and id ilike 'ABC%'"
+        ));
+        assert!(matching(
+            "state in (AVAILABLE, X) and count > 3 and tags contains x"
+        ));
+        assert!(!matching("id like 'b%'"));
+        assert!(!matching("count <= 4"));
+        assert!(!matching("missing = x"));
+    }
+
+    #[test]
+    fn state_query_uses_codes() {
+        for (state, expected) in [("STARTED", json!(600)), ("UNKNOWN", json!("UNKNOWN"))] {
+            let query = state_query(state, STATES);
+            let criterion = &query.filter_expression()[0];
+            assert_eq!(criterion.operand_left(), "state");
+            assert_eq!(criterion.operator(), "=");
+            assert_eq!(criterion.operand_right().0, expected);
+        }
+    }
+}
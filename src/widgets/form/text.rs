@@ -21,6 +21,9 @@ pub struct TextField {
     text: TextArea<'static>,
     #[builder(default)]
     selected: bool,
+    #[builder(default)]
+    #[allow(dead_code)]
+    masked: bool,
 }
 
 impl TextField {
@@ -55,6 +58,10 @@ impl TextFieldBuilder {
         text.set_cursor_line_style(Style::default());
         text.insert_str(self.initial_value.as_deref().unwrap_or_default());
 
+        if self.masked.unwrap_or_default() {
+            text.set_mask_char('*');
+        }
+
         if !selected {
             text.set_cursor_style(Style::default());
         }